@@ -3,11 +3,14 @@
 use std::path::PathBuf;
 use std::sync::Arc;
 
-use clap::{Parser, Subcommand};
+use clap::{Args, Parser, Subcommand};
 
+use moron_core::facade::DEFAULT_NARRATION_WPM;
 use moron_core::{
-    build_video, BuildConfig, BuildError, BuildProgress, DemoScene, M, Scene,
-    WhatIsMoronScene,
+    build_video, create_backend, AudioClip, BackendConfig, BuildConfig, BuildError,
+    BuildProgress, CachingBackend, CaptionConfig, DemoScene, Segment, SubtitleConfig,
+    VoiceBackend, VoiceBackendType, VoiceResolver, M, Scene, WhatIsMoronScene,
+    DEFAULT_SAMPLE_RATE,
 };
 
 #[derive(Parser)]
@@ -20,35 +23,74 @@ struct Cli {
 #[derive(Subcommand)]
 enum Commands {
     /// Render a project to video
-    Build {
-        /// Path to the project directory
-        #[arg(default_value = ".")]
-        path: String,
-
-        /// Output file path
-        #[arg(short, long, default_value = "output.mp4")]
-        output: String,
-
-        /// Path to the built React app's index.html
-        #[arg(long)]
-        html_path: Option<String>,
-
-        /// Output video width in pixels
-        #[arg(long, default_value = "1920")]
-        width: u32,
-
-        /// Output video height in pixels
-        #[arg(long, default_value = "1080")]
-        height: u32,
-
-        /// Keep intermediate frame PNGs (do not clean up temp directory)
-        #[arg(long)]
-        keep_frames: bool,
-
-        /// Scene to render: "what-is-moron" (default) or "demo"
-        #[arg(long, default_value = "what-is-moron")]
-        scene: String,
-    },
+    Build(BuildArgs),
+}
+
+#[derive(Args)]
+struct BuildArgs {
+    /// Path to the project directory
+    #[arg(default_value = ".")]
+    path: String,
+
+    /// Output file path
+    #[arg(short, long, default_value = "output.mp4")]
+    output: String,
+
+    /// Path to the built React app's index.html
+    #[arg(long)]
+    html_path: Option<String>,
+
+    /// Output video width in pixels
+    #[arg(long, default_value = "1920")]
+    width: u32,
+
+    /// Output video height in pixels
+    #[arg(long, default_value = "1080")]
+    height: u32,
+
+    /// Keep intermediate frame PNGs (do not clean up temp directory)
+    #[arg(long)]
+    keep_frames: bool,
+
+    /// Scene to render: "what-is-moron" (default) or "demo"
+    #[arg(long, default_value = "what-is-moron")]
+    scene: String,
+
+    /// Kokoro ONNX model file (defaults to $KOKORO_MODEL_PATH)
+    #[arg(long)]
+    kokoro_model: Option<PathBuf>,
+
+    /// Kokoro voices file (defaults to $KOKORO_VOICES_PATH)
+    #[arg(long)]
+    kokoro_voices: Option<PathBuf>,
+
+    /// Piper voice model, with its .onnx.json beside it (defaults to $PIPER_MODEL_PATH)
+    #[arg(long)]
+    piper_model: Option<PathBuf>,
+
+    /// Write .srt and .vtt captions next to the output video
+    #[arg(long)]
+    captions: bool,
+
+    /// Embed a toggleable subtitle track in the output video
+    #[arg(long)]
+    subtitles: bool,
+
+    /// Draw captions into the video frames
+    #[arg(long)]
+    burn_in_captions: bool,
+
+    /// ISO 639-2 language code for the subtitle track
+    #[arg(long, default_value = "eng")]
+    subtitle_language: String,
+
+    /// Directory for cached TTS audio (defaults to <path>/.moron/tts-cache)
+    #[arg(long)]
+    tts_cache: Option<PathBuf>,
+
+    /// Synthesize every narration, ignoring the TTS cache
+    #[arg(long, conflicts_with = "tts_cache")]
+    no_tts_cache: bool,
 }
 
 #[tokio::main]
@@ -56,16 +98,8 @@ async fn main() -> anyhow::Result<()> {
     let cli = Cli::parse();
 
     match cli.command {
-        Commands::Build {
-            path,
-            output,
-            html_path,
-            width,
-            height,
-            keep_frames,
-            scene,
-        } => {
-            run_build(path, output, html_path, width, height, keep_frames, scene).await?;
+        Commands::Build(args) => {
+            run_build(args).await?;
         }
     }
 
@@ -73,15 +107,29 @@ async fn main() -> anyhow::Result<()> {
 }
 
 /// Run the full build pipeline.
-async fn run_build(
-    path: String,
-    output: String,
-    html_path: Option<String>,
-    width: u32,
-    height: u32,
-    keep_frames: bool,
-    scene: String,
-) -> anyhow::Result<()> {
+async fn run_build(args: BuildArgs) -> anyhow::Result<()> {
+    let BuildArgs {
+        path,
+        output,
+        html_path,
+        width,
+        height,
+        keep_frames,
+        scene,
+        kokoro_model,
+        kokoro_voices,
+        piper_model,
+        captions,
+        subtitles,
+        burn_in_captions,
+        subtitle_language,
+        tts_cache,
+        no_tts_cache,
+    } = args;
+
+    let tts_cache_dir = (!no_tts_cache)
+        .then(|| tts_cache.unwrap_or_else(|| PathBuf::from(&path).join(".moron/tts-cache")));
+
     // Resolve the HTML path: CLI flag, or convention-based fallback.
     let resolved_html_path = resolve_html_path(&path, html_path.as_deref())?;
 
@@ -104,6 +152,12 @@ async fn run_build(
                     (current + 1) as f64 / total as f64 * 100.0,
                 );
             }
+            BuildProgress::TtsCacheHit { current, total } => {
+                eprintln!("[0/4] TTS cache hit {}/{}", current + 1, total);
+            }
+            BuildProgress::TtsCacheMiss { current, total } => {
+                eprintln!("[0/4] TTS cache miss {}/{}", current + 1, total);
+            }
             BuildProgress::SceneBuilt { total_duration, total_frames } => {
                 eprintln!(
                     "[1/4] Scene built: {total_frames} frames, {total_duration:.1}s"
@@ -134,6 +188,9 @@ async fn run_build(
         }
     });
 
+    let (voice_backend, voice_resolver) =
+        voice_backends(&m, kokoro_model, kokoro_voices, piper_model, tts_cache_dir)?;
+
     let config = BuildConfig {
         output_path: PathBuf::from(&output),
         html_path: resolved_html_path,
@@ -141,7 +198,12 @@ async fn run_build(
         height,
         keep_frames,
         progress: Some(progress),
-        voice_backend: None,
+        voice_backend,
+        voice_resolver,
+        caption_config: CaptionConfig::default(),
+        captions,
+        subtitles: subtitles.then(|| SubtitleConfig::default().with_language(subtitle_language)),
+        burn_in_captions,
     };
 
     match build_video(&mut m, config).await {
@@ -152,6 +214,9 @@ async fn run_build(
                 result.total_frames,
                 result.duration,
             );
+            for path in &result.captions {
+                println!("Captions: {}", path.display());
+            }
             Ok(())
         }
         Err(e) => {
@@ -160,6 +225,114 @@ async fn run_build(
     }
 }
 
+/// A voice backend shared between narrations.
+type SharedBackend = Arc<dyn VoiceBackend + Send + Sync>;
+
+/// Build the TTS backends from CLI flags and the environment: a per-voice
+/// lookup, plus a default backend for narrations recorded before the scene
+/// picks a voice.
+///
+/// Flags take precedence over `KOKORO_MODEL_PATH` / `KOKORO_VOICES_PATH` /
+/// `PIPER_MODEL_PATH`. Without any model files, TTS is disabled and
+/// narration is silent, unless the scene narrates with pre-recorded voices.
+/// The default backend uses whichever engine is configured (Kokoro first);
+/// with neither, narrations without a voice stay silent.
+///
+/// With a `tts_cache` directory, Kokoro and Piper output is cached there,
+/// keyed by text, voice and model file. Recordings are never cached.
+fn voice_backends(
+    m: &M,
+    kokoro_model: Option<PathBuf>,
+    kokoro_voices: Option<PathBuf>,
+    piper_model: Option<PathBuf>,
+    tts_cache: Option<PathBuf>,
+) -> anyhow::Result<(Option<SharedBackend>, Option<VoiceResolver>)> {
+    let mut backends = BackendConfig::from_env();
+    if kokoro_model.is_some() {
+        backends.kokoro_model_path = kokoro_model;
+    }
+    if kokoro_voices.is_some() {
+        backends.kokoro_voices_path = kokoro_voices;
+    }
+    if piper_model.is_some() {
+        backends.piper_model_path = piper_model;
+    }
+
+    let uses_recordings = m.timeline().segments().iter().any(|segment| {
+        matches!(
+            segment,
+            Segment::Narration { voice: Some(voice), .. }
+                if matches!(voice.backend_type, VoiceBackendType::PreRecorded(_))
+        )
+    });
+
+    let default_voice = backends.default_voice();
+    if default_voice.is_none() && !uses_recordings {
+        eprintln!(
+            "Note: TTS disabled (no voice model). Narration will be silent.\n\
+             Pass --kokoro-model and --kokoro-voices (or --piper-model), or set \
+             KOKORO_MODEL_PATH and KOKORO_VOICES_PATH (or PIPER_MODEL_PATH)."
+        );
+        return Ok((None, None));
+    }
+
+    let resolver = voice_resolver(backends, tts_cache);
+    let default: SharedBackend = match default_voice {
+        Some(voice) => resolver(&voice)?,
+        None => Arc::new(SilentBackend),
+    };
+    Ok((Some(default), Some(resolver)))
+}
+
+/// Build the per-voice backend lookup over `backends`, caching Kokoro and
+/// Piper output under `tts_cache` if given.
+fn voice_resolver(backends: BackendConfig, tts_cache: Option<PathBuf>) -> VoiceResolver {
+    Arc::new(move |voice| {
+        let backend = create_backend(voice, &backends)?;
+        let Some(dir) = &tts_cache else {
+            return Ok(backend);
+        };
+        // Every file that shapes the audio goes into the cache key.
+        let models = match voice.backend_type {
+            VoiceBackendType::Kokoro => [
+                backends.kokoro_model_path.clone(),
+                backends.kokoro_voices_path.clone(),
+            ],
+            VoiceBackendType::Piper => [
+                backends.piper_model_path.clone(),
+                backends.piper_model_path.as_ref().map(|model| {
+                    let mut config = model.clone().into_os_string();
+                    config.push(".json");
+                    PathBuf::from(config)
+                }),
+            ],
+            _ => return Ok(backend),
+        };
+        let cached = models
+            .into_iter()
+            .flatten()
+            .fold(CachingBackend::new(backend, dir).with_voice(voice), |cached, model| {
+                cached.with_model(model)
+            });
+        Ok(Arc::new(cached))
+    })
+}
+
+/// Speaks nothing: each narration becomes silence lasting its estimated
+/// reading time, as if TTS were off.
+struct SilentBackend;
+
+impl VoiceBackend for SilentBackend {
+    fn synthesize(&self, text: &str) -> Result<AudioClip, anyhow::Error> {
+        let words = text.split_whitespace().count().max(1) as f64;
+        Ok(AudioClip::silence(words * 60.0 / DEFAULT_NARRATION_WPM, DEFAULT_SAMPLE_RATE))
+    }
+
+    fn name(&self) -> &str {
+        "silent"
+    }
+}
+
 /// Resolve the path to the React app's index.html.
 ///
 /// Priority:
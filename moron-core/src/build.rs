@@ -13,16 +13,19 @@
 //!
 //! The CLI (`moron build`) is a thin wrapper around this function.
 
+use std::ops::Range;
 use std::path::PathBuf;
 use std::sync::Arc;
 
-use moron_voice::{AudioClip, VoiceBackend};
+use moron_voice::{alignment, AudioClip, Voice, VoiceBackend, VoiceBackendType};
 
+use crate::captions::{self, CaptionConfig, SubtitleConfig};
 use crate::chromium::BridgeConfig;
 use crate::facade::M;
-use crate::ffmpeg::{self, EncodeConfig, FfmpegError};
+use crate::ffmpeg::{self, EncodeConfig, FfmpegError, SubtitleTrack};
 use crate::renderer::{self, RenderConfig, RenderError, RenderProgress};
-use crate::timeline::Segment;
+use crate::soundtrack;
+use crate::timeline::{Segment, Timeline};
 
 // ---------------------------------------------------------------------------
 // BuildError
@@ -74,6 +77,21 @@ pub enum BuildProgress {
         /// Total number of narration segments.
         total: usize,
     },
+    /// A narration's audio was read from the TTS cache instead of being
+    /// synthesized.
+    TtsCacheHit {
+        /// The narration segment (0-indexed).
+        current: usize,
+        /// Total number of narration segments.
+        total: usize,
+    },
+    /// A narration was not in the TTS cache and is being synthesized.
+    TtsCacheMiss {
+        /// The narration segment (0-indexed).
+        current: usize,
+        /// Total number of narration segments.
+        total: usize,
+    },
     /// The scene has been analyzed; rendering is about to begin.
     SceneBuilt {
         /// Total duration of the timeline in seconds.
@@ -107,6 +125,14 @@ pub enum BuildProgress {
 // BuildConfig
 // ---------------------------------------------------------------------------
 
+/// Maps a [`Voice`] recorded on a narration to the backend that speaks it.
+///
+/// Called at most once per distinct voice during a build; the resulting
+/// backend is reused for every narration with that voice.
+pub type VoiceResolver = Arc<
+    dyn Fn(&Voice) -> Result<Arc<dyn VoiceBackend + Send + Sync>, anyhow::Error> + Send + Sync,
+>;
+
 /// Configuration for the full build pipeline.
 pub struct BuildConfig {
     /// Path to the final output `.mp4` file.
@@ -127,7 +153,43 @@ pub struct BuildConfig {
     /// When `Some`, narration segments are synthesized before frame rendering
     /// and the resulting audio is wired into the final .mp4. When `None`,
     /// all narration segments produce silence (backward-compatible).
+    ///
+    /// When [`voice_resolver`](Self::voice_resolver) is also set, this backend
+    /// only handles narrations that carry no voice, i.e. those recorded before
+    /// the scene first called [`M::voice`].
     pub voice_backend: Option<Arc<dyn VoiceBackend + Send + Sync>>,
+    /// Optional per-voice backend lookup.
+    ///
+    /// When `Some`, each narration is synthesized by the backend resolved
+    /// from the [`Voice`] that was active when it was recorded, so a scene
+    /// can switch narrators between sections. Narrations without a voice go
+    /// to [`voice_backend`](Self::voice_backend), or, if that is `None`, to
+    /// the backend resolved for [`Voice::kokoro`], the voice `M` starts with.
+    pub voice_resolver: Option<VoiceResolver>,
+    /// Rules for splitting narration into caption cues.
+    ///
+    /// Shared by [`captions`](Self::captions),
+    /// [`subtitles`](Self::subtitles) and
+    /// [`burn_in_captions`](Self::burn_in_captions), so all three show the
+    /// same cues.
+    pub caption_config: CaptionConfig,
+    /// Whether to export captions.
+    ///
+    /// When `true`, `.srt` and `.vtt` caption files are written next to the
+    /// output `.mp4`.
+    pub captions: bool,
+    /// Optional soft subtitle track.
+    ///
+    /// When `Some`, captions generated from the narration are embedded in
+    /// the output `.mp4` as a `mov_text` stream tagged with the config's
+    /// language, so players can toggle them. A scene without narration gets
+    /// no track.
+    pub subtitles: Option<SubtitleConfig>,
+    /// Whether to draw captions into the video frames themselves.
+    ///
+    /// Off by default; a video that also carries a subtitle track would
+    /// otherwise show every line twice.
+    pub burn_in_captions: bool,
 }
 
 impl BuildConfig {
@@ -136,7 +198,9 @@ impl BuildConfig {
     /// - Resolution: 1920x1080
     /// - keep_frames: false
     /// - No progress callback
-    /// - No TTS backend (all narration is silence)
+    /// - No TTS backend or voice resolver (all narration is silence)
+    /// - Default caption rules, with no caption export, subtitle track or
+    ///   burned-in captions
     pub fn new(output_path: impl Into<PathBuf>, html_path: impl Into<PathBuf>) -> Self {
         Self {
             output_path: output_path.into(),
@@ -146,6 +210,11 @@ impl BuildConfig {
             keep_frames: false,
             progress: None,
             voice_backend: None,
+            voice_resolver: None,
+            caption_config: CaptionConfig::default(),
+            captions: false,
+            subtitles: None,
+            burn_in_captions: false,
         }
     }
 }
@@ -162,6 +231,8 @@ pub struct BuildResult {
     pub total_frames: u32,
     /// Duration of the video in seconds.
     pub duration: f64,
+    /// Caption files written next to the video (empty unless enabled).
+    pub captions: Vec<PathBuf>,
 }
 
 // ---------------------------------------------------------------------------
@@ -173,14 +244,21 @@ pub struct BuildResult {
 /// Given a built scene (`M` with a recorded timeline) and a [`BuildConfig`],
 /// this function:
 ///
-/// 1. Synthesizes TTS for narration segments (if `voice_backend` is configured)
-/// 2. Resolves narration durations from actual TTS output
+/// 1. Synthesizes TTS for narration segments (if `voice_backend` or
+///    `voice_resolver` is configured), routing each to its voice's backend
+/// 2. Resolves narration durations and word timings from actual TTS output
 /// 3. Reports scene statistics
 /// 4. Creates a temporary directory for intermediate files
-/// 5. Renders frames via the Chromium bridge (timing matches TTS durations)
+/// 5. Renders frames via the Chromium bridge (timing matches TTS durations),
+///    replacing the frames under each video clip with the clip's own frames
+///    and drawing captions into them if `burn_in_captions` is set
 /// 6. Encodes frames into a video-only `.mp4` via FFmpeg
-/// 7. Assembles an audio track (real TTS audio for narrations, silence for gaps)
-/// 8. Muxes video + audio into the final `.mp4`
+/// 7. Assembles an audio track (TTS audio for narrations, clip audio for
+///    video clips, silence for gaps), then mixes in background music
+///    (ducked while narration plays) and sound effects
+/// 8. Muxes video + audio (plus a soft subtitle track, if `subtitles` is
+///    configured) into the final `.mp4`, and writes `.srt` / `.vtt` captions
+///    next to it (if `captions` is set)
 /// 9. Cleans up intermediate files (unless `keep_frames` is set)
 ///
 /// # Errors
@@ -191,11 +269,14 @@ pub async fn build_video(m: &mut M, config: BuildConfig) -> Result<BuildResult,
     // Step 0: Synthesize TTS (if backend available)
     // -----------------------------------------------------------------------
 
-    let narration_clips = match &config.voice_backend {
-        Some(backend) => {
-            Some(synthesize_narrations(m, backend.as_ref(), &config.progress)?)
-        }
-        None => None,
+    let mut voices = VoiceRouter::new(
+        config.voice_backend.clone(),
+        config.voice_resolver.clone(),
+    );
+    let narration_clips = if voices.is_enabled() {
+        Some(synthesize_narrations(m, &mut voices, &config.progress)?)
+    } else {
+        None
     };
 
     // Recompute timeline stats after potential duration resolution.
@@ -250,6 +331,8 @@ pub async fn build_video(m: &mut M, config: BuildConfig) -> Result<BuildResult,
         None => None,
     };
 
+    apply_burn_in(m, &config);
+
     let render_config = RenderConfig {
         output_dir: frames_dir.clone(),
         bridge_config,
@@ -258,6 +341,19 @@ pub async fn build_video(m: &mut M, config: BuildConfig) -> Result<BuildResult,
 
     renderer::render(m, render_config).await?;
 
+    // Video clips replace the rendered frames for their time range.
+    for (path, range) in clip_frame_ranges(m.timeline(), total_frames) {
+        ffmpeg::extract_clip_frames(
+            path,
+            &frames_dir,
+            range.start,
+            range.len() as u32,
+            fps,
+            config.width,
+            config.height,
+        )?;
+    }
+
     // -----------------------------------------------------------------------
     // Step 2: Encode video (frames -> video-only .mp4)
     // -----------------------------------------------------------------------
@@ -276,23 +372,53 @@ pub async fn build_video(m: &mut M, config: BuildConfig) -> Result<BuildResult,
 
     report(&config.progress, BuildProgress::MuxingAudio);
 
-    // Determine sample rate: use the TTS output sample rate when available,
-    // fall back to the default broadcast sample rate otherwise.
-    let sample_rate = narration_clips
-        .as_ref()
-        .and_then(|clips| clips.first())
-        .map(|c| c.sample_rate)
-        .unwrap_or(moron_voice::DEFAULT_SAMPLE_RATE);
+    // Mix at the broadcast rate; narration from every voice is resampled to it.
+    let sample_rate = moron_voice::DEFAULT_SAMPLE_RATE;
+
+    let clip_audio = m
+        .timeline()
+        .segments()
+        .iter()
+        .filter_map(|seg| match seg {
+            Segment::Clip { path, duration } => {
+                Some(ffmpeg::extract_clip_audio(path, *duration, sample_rate))
+            }
+            _ => None,
+        })
+        .collect::<Result<Vec<_>, _>>()?;
 
     let audio_clip = ffmpeg::assemble_audio_track(
         m.timeline(),
         sample_rate,
         narration_clips.as_deref(),
-    )?;
+        Some(&clip_audio),
+    );
+    let audio_clip = soundtrack::add_soundtrack(audio_clip, m)?;
     let wav_bytes = audio_clip.to_wav_bytes();
     std::fs::write(&audio_path, &wav_bytes)?;
 
-    ffmpeg::mux_audio(&video_only_path, &audio_path, &config.output_path)?;
+    let subtitle_track = match &config.subtitles {
+        Some(subtitles) => write_subtitles(
+            m,
+            &temp_dir.join("subtitles.srt"),
+            &config.caption_config,
+            subtitles,
+        )?,
+        None => None,
+    };
+
+    ffmpeg::mux_streams(
+        &video_only_path,
+        &audio_path,
+        subtitle_track.as_ref(),
+        &config.output_path,
+    )?;
+
+    let caption_paths = if config.captions {
+        captions::write_captions(m, &config.output_path, &config.caption_config)?
+    } else {
+        Vec::new()
+    };
 
     // -----------------------------------------------------------------------
     // Step 4: Clean up
@@ -314,9 +440,40 @@ pub async fn build_video(m: &mut M, config: BuildConfig) -> Result<BuildResult,
         output_path: config.output_path,
         total_frames,
         duration: total_duration,
+        captions: caption_paths,
     })
 }
 
+/// Turn burned-in captions on or off for rendering, as `config` says.
+///
+/// [`BuildConfig::burn_in_captions`] is the only switch: whatever `m` had
+/// before is replaced.
+fn apply_burn_in(m: &mut M, config: &BuildConfig) {
+    m.set_burned_in_captions(
+        config
+            .burn_in_captions
+            .then(|| config.caption_config.clone()),
+    );
+}
+
+/// Write the subtitle file for `m` to `path` and describe the track.
+///
+/// Returns `None` without writing anything when there are no cues, since
+/// FFmpeg rejects an empty subtitle input.
+fn write_subtitles(
+    m: &M,
+    path: &std::path::Path,
+    rules: &CaptionConfig,
+    subtitles: &SubtitleConfig,
+) -> Result<Option<SubtitleTrack>, std::io::Error> {
+    let cues = captions::caption_cues(m, rules);
+    if cues.is_empty() {
+        return Ok(None);
+    }
+    std::fs::write(path, captions::to_srt(&cues))?;
+    Ok(Some(SubtitleTrack::new(path, subtitles.language.clone())))
+}
+
 // ---------------------------------------------------------------------------
 // synthesize_narrations
 // ---------------------------------------------------------------------------
@@ -325,15 +482,21 @@ pub async fn build_video(m: &mut M, config: BuildConfig) -> Result<BuildResult,
 ///
 /// For each narration segment, this function:
 /// 1. Extracts the text from the segment
-/// 2. Calls `backend.synthesize(text)` to produce an [`AudioClip`]
-/// 3. Reports `SynthesizingTts` progress
+/// 2. Picks the backend for the segment's voice and calls
+///    `synthesize(text)` to produce an [`AudioClip`]
+/// 3. Reports `SynthesizingTts` progress, plus `TtsCacheHit` /
+///    `TtsCacheMiss` for backends with a cache
 /// 4. Collects the resulting durations
 /// 5. Calls `m.resolve_narration_durations()` to update the timeline
 ///
+/// Backends that play back a recording must be used by exactly as many
+/// narrations as the recording has sections; a mismatch is an error rather
+/// than audio silently shifting to the wrong narration.
+///
 /// Returns the synthesized clips in timeline order (one per narration segment).
 fn synthesize_narrations(
     m: &mut M,
-    backend: &dyn VoiceBackend,
+    voices: &mut VoiceRouter,
     progress: &Option<Arc<dyn Fn(BuildProgress) + Send + Sync>>,
 ) -> Result<Vec<AudioClip>, BuildError> {
     let narration_indices = m.timeline().narration_indices();
@@ -343,32 +506,60 @@ fn synthesize_narrations(
         return Ok(Vec::new());
     }
 
-    // Collect narration texts.
-    let texts: Vec<String> = narration_indices
+    // Collect narration texts and voices.
+    let narrations: Vec<(String, Option<Voice>)> = narration_indices
         .iter()
         .filter_map(|&idx| {
             match &m.timeline().segments()[idx] {
-                Segment::Narration { text, .. } => Some(text.clone()),
+                Segment::Narration { text, voice, .. } => Some((text.clone(), voice.clone())),
                 _ => None,
             }
         })
         .collect();
 
+    // Pick every narration's backend up front so recordings can be checked
+    // against the number of narrations that use them.
+    let backends = narrations
+        .iter()
+        .enumerate()
+        .map(|(i, (_, voice))| {
+            voices.backend_for(voice.as_ref()).map_err(|e| BuildError::Tts {
+                segment: i,
+                source: e,
+            })
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    check_recordings(&backends)?;
+
     // Synthesize each narration.
     let mut clips = Vec::with_capacity(total);
     let mut durations = Vec::with_capacity(total);
+    let mut timings = Vec::with_capacity(total);
 
-    for (i, text) in texts.iter().enumerate() {
+    for (i, ((text, _), backend)) in narrations.iter().zip(&backends).enumerate() {
         report(progress, BuildProgress::SynthesizingTts {
             current: i,
             total,
         });
 
-        let clip = backend.synthesize(text).map_err(|e| BuildError::Tts {
-            segment: i,
-            source: e,
-        })?;
+        let cache_event = match backend.is_cached(text) {
+            Some(true) => Some(BuildProgress::TtsCacheHit { current: i, total }),
+            Some(false) => Some(BuildProgress::TtsCacheMiss { current: i, total }),
+            None => None,
+        };
+        if let Some(event) = cache_event {
+            report(progress, event);
+        }
 
+        let (clip, words) = backend
+            .synthesize_with_timings(text)
+            .map_err(|e| BuildError::Tts {
+                segment: i,
+                source: e,
+            })?;
+
+        // Backends that don't report word timings are aligned offline.
+        timings.push(words.unwrap_or_else(|| alignment::align(text, &clip)));
         durations.push(clip.duration());
         clips.push(clip);
     }
@@ -377,10 +568,141 @@ fn synthesize_narrations(
     m.resolve_narration_durations(&durations).map_err(|e| {
         BuildError::Config(format!("failed to resolve narration durations: {e}"))
     })?;
+    m.resolve_word_timings(timings).map_err(|e| {
+        BuildError::Config(format!("failed to resolve word timings: {e}"))
+    })?;
 
     Ok(clips)
 }
 
+/// Check that every recording backend in `backends` (one per narration) is
+/// used by exactly as many narrations as it has sections.
+fn check_recordings(backends: &[Arc<dyn VoiceBackend + Send + Sync>]) -> Result<(), BuildError> {
+    for (first, backend) in backends.iter().enumerate() {
+        let Some(sections) = backend.recorded_sections() else {
+            continue;
+        };
+        // Only check each backend once, at its first narration.
+        if backends[..first].iter().any(|b| Arc::ptr_eq(b, backend)) {
+            continue;
+        }
+        let uses = backends.iter().filter(|b| Arc::ptr_eq(b, backend)).count();
+        if uses != sections {
+            return Err(BuildError::Tts {
+                segment: first,
+                source: anyhow::anyhow!(
+                    "recording has {sections} section(s) but {uses} narration(s) use it"
+                ),
+            });
+        }
+    }
+    Ok(())
+}
+
+// ---------------------------------------------------------------------------
+// clip_frame_ranges
+// ---------------------------------------------------------------------------
+
+/// Frame ranges covered by each video clip segment, in timeline order.
+///
+/// A frame belongs to a clip when its timestamp (`frame / fps`) falls inside
+/// the clip's time span. Ranges are clamped to `total_frames` and empty
+/// ranges are skipped.
+fn clip_frame_ranges(
+    timeline: &Timeline,
+    total_frames: u32,
+) -> Vec<(&std::path::Path, Range<u32>)> {
+    let fps = timeline.fps() as f64;
+    // Tolerance so a start landing exactly on a frame boundary includes it.
+    let to_frame = |t: f64| ((t * fps - 1e-9).ceil().max(0.0) as u32).min(total_frames);
+
+    timeline
+        .segments()
+        .iter()
+        .zip(timeline.segment_starts())
+        .filter_map(|(seg, start)| match seg {
+            Segment::Clip { path, duration } => {
+                let range = to_frame(start)..to_frame(start + duration);
+                (!range.is_empty()).then_some((path.as_path(), range))
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+// ---------------------------------------------------------------------------
+// VoiceRouter
+// ---------------------------------------------------------------------------
+
+/// Picks the backend for each narration and caches resolved backends.
+struct VoiceRouter {
+    /// Backend for narrations without a voice (or when there's no resolver).
+    default: Option<Arc<dyn VoiceBackend + Send + Sync>>,
+    /// Per-voice lookup, if configured.
+    resolver: Option<VoiceResolver>,
+    /// Backends resolved so far, keyed by [`route_key`].
+    resolved: Vec<(Voice, Arc<dyn VoiceBackend + Send + Sync>)>,
+}
+
+impl VoiceRouter {
+    fn new(
+        default: Option<Arc<dyn VoiceBackend + Send + Sync>>,
+        resolver: Option<VoiceResolver>,
+    ) -> Self {
+        Self {
+            default,
+            resolver,
+            resolved: Vec::new(),
+        }
+    }
+
+    /// Whether any narration can be synthesized at all.
+    fn is_enabled(&self) -> bool {
+        self.default.is_some() || self.resolver.is_some()
+    }
+
+    /// The backend that should speak a narration recorded with `voice`.
+    ///
+    /// A narration with a voice goes to the resolver when there is one. A
+    /// narration without one goes to the default backend, falling back to
+    /// the resolver's backend for `M`'s initial voice.
+    fn backend_for(
+        &mut self,
+        voice: Option<&Voice>,
+    ) -> Result<Arc<dyn VoiceBackend + Send + Sync>, anyhow::Error> {
+        let voice = match voice {
+            Some(voice) => Some(voice.clone()),
+            None if self.default.is_none() => Some(Voice::kokoro()),
+            None => None,
+        };
+        if let (Some(resolver), Some(voice)) = (&self.resolver, &voice) {
+            let key = route_key(voice);
+            if let Some((_, backend)) = self.resolved.iter().find(|(v, _)| *v == key) {
+                return Ok(Arc::clone(backend));
+            }
+            let backend = resolver(&key)?;
+            self.resolved.push((key, Arc::clone(&backend)));
+            return Ok(backend);
+        }
+
+        self.default
+            .clone()
+            .ok_or_else(|| anyhow::anyhow!("no voice backend configured for this narration"))
+    }
+}
+
+/// The voice a narration is routed by.
+///
+/// Recordings are keyed by file alone: one file is one sequence of sections,
+/// so every narration using it must share a backend whatever its other
+/// settings.
+fn route_key(voice: &Voice) -> Voice {
+    match &voice.backend_type {
+        VoiceBackendType::PreRecorded(path) => Voice::file(path),
+        _ => voice.clone(),
+    }
+}
+
 /// Helper to invoke the progress callback if present.
 fn report(progress: &Option<Arc<dyn Fn(BuildProgress) + Send + Sync>>, event: BuildProgress) {
     if let Some(cb) = progress {
@@ -406,6 +728,11 @@ mod tests {
         assert!(!config.keep_frames);
         assert!(config.progress.is_none());
         assert!(config.voice_backend.is_none());
+        assert!(config.voice_resolver.is_none());
+        assert_eq!(config.caption_config, CaptionConfig::default());
+        assert!(!config.captions);
+        assert!(config.subtitles.is_none());
+        assert!(!config.burn_in_captions);
     }
 
     #[test]
@@ -458,6 +785,7 @@ mod tests {
             output_path: PathBuf::from("out.mp4"),
             total_frames: 90,
             duration: 3.0,
+            captions: Vec::new(),
         };
         assert_eq!(result.output_path, PathBuf::from("out.mp4"));
         assert_eq!(result.total_frames, 90);
@@ -526,7 +854,9 @@ mod tests {
             seconds_per_word: 0.5,
         };
 
-        let clips = synthesize_narrations(&mut m, &backend, &None).unwrap();
+        let mut voices = VoiceRouter::new(Some(Arc::new(backend)), None);
+
+        let clips = synthesize_narrations(&mut m, &mut voices, &None).unwrap();
 
         // "hello world" = 2 words * 0.5 = 1.0s
         // "goodbye" = 1 word * 0.5 = 0.5s
@@ -536,6 +866,57 @@ mod tests {
 
         // After resolution: total = 1.0 + 0.5 + 0.5 = 2.0s
         assert!((m.timeline().total_duration() - 2.0).abs() < 1e-10);
+
+        // The mock's silent audio is aligned by word length.
+        let words = m.word_timings(0).unwrap();
+        assert_eq!(words.len(), 2);
+        assert_eq!(words[1].word, "world");
+        assert!((words[1].end - 1.0).abs() < 1e-10);
+    }
+
+    /// A backend that reports its own word timings.
+    struct TimedBackend;
+
+    impl VoiceBackend for TimedBackend {
+        fn synthesize(&self, text: &str) -> Result<AudioClip, anyhow::Error> {
+            Ok(self.synthesize_with_timings(text)?.0)
+        }
+
+        fn synthesize_with_timings(
+            &self,
+            text: &str,
+        ) -> Result<(AudioClip, Option<Vec<moron_voice::WordTiming>>), anyhow::Error> {
+            let words = text
+                .split_whitespace()
+                .enumerate()
+                .map(|(i, word)| moron_voice::WordTiming {
+                    word: word.to_string(),
+                    start: i as f64,
+                    end: i as f64 + 0.5,
+                })
+                .collect();
+            Ok((AudioClip::silence(3.0, 48000), Some(words)))
+        }
+
+        fn name(&self) -> &str {
+            "timed-mock"
+        }
+    }
+
+    #[test]
+    fn synthesize_narrations_keeps_backend_word_timings() {
+        let mut m = M::new();
+        m.narrate("one two three");
+
+        let mut voices = VoiceRouter::new(Some(Arc::new(TimedBackend)), None);
+        // The narration has no voice (no `m.voice()` call), so it goes to the
+        // default backend.
+        synthesize_narrations(&mut m, &mut voices, &None).unwrap();
+
+        let words = m.word_timings(0).unwrap();
+        assert_eq!(words[2].word, "three");
+        assert!((words[2].start - 2.0).abs() < 1e-10);
+        assert!((words[2].end - 2.5).abs() < 1e-10);
     }
 
     #[test]
@@ -548,7 +929,9 @@ mod tests {
             seconds_per_word: 0.5,
         };
 
-        let clips = synthesize_narrations(&mut m, &backend, &None).unwrap();
+        let mut voices = VoiceRouter::new(Some(Arc::new(backend)), None);
+
+        let clips = synthesize_narrations(&mut m, &mut voices, &None).unwrap();
         assert!(clips.is_empty());
 
         // Duration unchanged
@@ -574,11 +957,55 @@ mod tests {
             }
         });
 
-        let clips = synthesize_narrations(&mut m, &backend, &Some(cb)).unwrap();
+        let mut voices = VoiceRouter::new(Some(Arc::new(backend)), None);
+
+        let clips = synthesize_narrations(&mut m, &mut voices, &Some(cb)).unwrap();
         assert_eq!(clips.len(), 2);
         assert_eq!(count.load(std::sync::atomic::Ordering::SeqCst), 2);
     }
 
+    #[test]
+    fn synthesize_narrations_reports_cache_hits() {
+        use std::sync::Mutex;
+
+        let dir = std::env::temp_dir().join("moron_test_build_tts_cache");
+        let _ = std::fs::remove_dir_all(&dir);
+
+        let events = Arc::new(Mutex::new(Vec::new()));
+        let sink = Arc::clone(&events);
+        let cb: Arc<dyn Fn(BuildProgress) + Send + Sync> = Arc::new(move |event| {
+            let hit = match event {
+                BuildProgress::TtsCacheHit { current, .. } => (current, true),
+                BuildProgress::TtsCacheMiss { current, .. } => (current, false),
+                _ => return,
+            };
+            sink.lock().unwrap().push(hit);
+        });
+        let cached = || {
+            let inner = MockBackend {
+                sample_rate: 48000,
+                seconds_per_word: 0.5,
+            };
+            let backend: Arc<dyn VoiceBackend + Send + Sync> =
+                Arc::new(moron_voice::CachingBackend::new(Arc::new(inner), &dir));
+            VoiceRouter::new(Some(backend), None)
+        };
+
+        let mut m = M::new();
+        m.narrate("one");
+        synthesize_narrations(&mut m, &mut cached(), &Some(Arc::clone(&cb))).unwrap();
+
+        let mut m = M::new();
+        m.narrate("one");
+        m.narrate("two");
+        let clips = synthesize_narrations(&mut m, &mut cached(), &Some(cb)).unwrap();
+        assert!((clips[0].duration() - 0.5).abs() < 1e-10);
+
+        assert_eq!(*events.lock().unwrap(), vec![(0, false), (0, true), (1, false)]);
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
     /// A mock backend that always fails synthesis.
     struct FailingBackend;
 
@@ -598,7 +1025,8 @@ mod tests {
         m.narrate("hello");
 
         let backend = FailingBackend;
-        let result = synthesize_narrations(&mut m, &backend, &None);
+        let mut voices = VoiceRouter::new(Some(Arc::new(backend)), None);
+        let result = synthesize_narrations(&mut m, &mut voices, &None);
 
         assert!(result.is_err());
         let err = result.unwrap_err();
@@ -606,4 +1034,241 @@ mod tests {
         let msg = format!("{err}");
         assert!(msg.contains("synthesis engine crashed"));
     }
+
+    #[test]
+    fn clip_frame_ranges_cover_clip_segments() {
+        use crate::timeline::TimelineBuilder;
+
+        let tl = TimelineBuilder::new()
+            .fps(30)
+            .silence(1.0)
+            .clip("intro.mp4", 2.0)
+            .silence(0.5)
+            .build();
+
+        let ranges = clip_frame_ranges(&tl, tl.total_frames());
+        assert_eq!(ranges.len(), 1);
+        assert_eq!(ranges[0].0, std::path::Path::new("intro.mp4"));
+        assert_eq!(ranges[0].1, 30..90);
+    }
+
+    #[test]
+    fn clip_frame_ranges_clamped_to_total_frames() {
+        use crate::timeline::TimelineBuilder;
+
+        let tl = TimelineBuilder::new().fps(30).clip("a.mp4", 1.0).build();
+        let ranges = clip_frame_ranges(&tl, 20);
+        assert_eq!(ranges[0].1, 0..20);
+
+        let ranges = clip_frame_ranges(&tl, 0);
+        assert!(ranges.is_empty());
+    }
+
+    #[test]
+    fn synthesize_narrations_routes_by_voice() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let mut m = M::new();
+        m.narrate("one");
+        m.voice(Voice::piper());
+        m.narrate("two words");
+        m.voice(Voice::kokoro());
+        m.narrate("three");
+
+        let calls = Arc::new(AtomicUsize::new(0));
+        let counter = Arc::clone(&calls);
+        let resolver: VoiceResolver = Arc::new(move |voice: &Voice| {
+            counter.fetch_add(1, Ordering::SeqCst);
+            let seconds_per_word = match voice.backend_type {
+                moron_voice::VoiceBackendType::Piper => 0.25,
+                _ => 1.0,
+            };
+            let backend: Arc<dyn VoiceBackend + Send + Sync> = Arc::new(MockBackend {
+                sample_rate: 48000,
+                seconds_per_word,
+            });
+            Ok(backend)
+        });
+
+        let mut voices = VoiceRouter::new(None, Some(resolver));
+        let clips = synthesize_narrations(&mut m, &mut voices, &None).unwrap();
+
+        assert!((clips[0].duration() - 1.0).abs() < 1e-10);
+        assert!((clips[1].duration() - 0.5).abs() < 1e-10);
+        assert!((clips[2].duration() - 1.0).abs() < 1e-10);
+        // Kokoro is resolved once and reused for the third narration.
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+    }
+
+    #[test]
+    fn synthesize_narrations_without_voice_uses_default() {
+        let mut m = M::new();
+        m.narrate("hello");
+
+        let resolver: VoiceResolver =
+            Arc::new(|_: &Voice| Err(anyhow::anyhow!("resolver should not be used")));
+        let default = MockBackend {
+            sample_rate: 48000,
+            seconds_per_word: 0.5,
+        };
+        let mut voices = VoiceRouter::new(Some(Arc::new(default)), Some(resolver));
+
+        // No voice was chosen, so the default backend speaks.
+        let clips = synthesize_narrations(&mut m, &mut voices, &None).unwrap();
+        assert!((clips[0].duration() - 0.5).abs() < 1e-10);
+
+        // Once the scene picks a voice, the resolver takes over.
+        m.voice(Voice::piper());
+        m.narrate("again");
+        let err = synthesize_narrations(&mut m, &mut voices, &None).unwrap_err();
+        assert!(format!("{err}").contains("resolver should not be used"));
+    }
+
+    #[test]
+    fn synthesize_narrations_with_only_piper_configured() {
+        // Set up like the CLI with only a Piper model: Kokoro can't be
+        // built, and the default backend comes from the configured engine.
+        let config = moron_voice::BackendConfig::new().with_piper("/m/voice.onnx");
+        let resolver: VoiceResolver = Arc::new(|voice: &Voice| match voice.backend_type {
+            VoiceBackendType::Piper => {
+                let backend: Arc<dyn VoiceBackend + Send + Sync> = Arc::new(MockBackend {
+                    sample_rate: 22050,
+                    seconds_per_word: 0.5,
+                });
+                Ok(backend)
+            }
+            _ => Err(anyhow::anyhow!("kokoro backend is not configured")),
+        });
+        let default = resolver(&config.default_voice().unwrap()).unwrap();
+        let mut voices = VoiceRouter::new(Some(default), Some(resolver));
+
+        let mut m = M::new();
+        m.narrate("hello there");
+        m.voice(Voice::piper().with_speed(1.2));
+        m.narrate("again");
+
+        let clips = synthesize_narrations(&mut m, &mut voices, &None).unwrap();
+        assert_eq!(clips.len(), 2);
+        assert!((clips[0].duration() - 1.0).abs() < 1e-10);
+    }
+
+    #[test]
+    fn synthesize_narrations_without_voice_or_default_uses_initial_voice() {
+        let mut m = M::new();
+        m.narrate("hello");
+
+        let resolver: VoiceResolver = Arc::new(|voice: &Voice| {
+            assert_eq!(voice, &Voice::kokoro());
+            let backend: Arc<dyn VoiceBackend + Send + Sync> = Arc::new(MockBackend {
+                sample_rate: 48000,
+                seconds_per_word: 0.25,
+            });
+            Ok(backend)
+        });
+        let mut voices = VoiceRouter::new(None, Some(resolver));
+
+        let clips = synthesize_narrations(&mut m, &mut voices, &None).unwrap();
+        assert!((clips[0].duration() - 0.25).abs() < 1e-10);
+    }
+
+    #[test]
+    fn recordings_are_routed_by_file() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let mut m = M::new();
+        m.voice(Voice::file("vo.wav"));
+        m.narrate("one");
+        m.voice(Voice::file("vo.wav").with_speed(1.5));
+        m.narrate("two");
+
+        let calls = Arc::new(AtomicUsize::new(0));
+        let counter = Arc::clone(&calls);
+        let resolver: VoiceResolver = Arc::new(move |voice: &Voice| {
+            counter.fetch_add(1, Ordering::SeqCst);
+            assert_eq!(voice, &Voice::file("vo.wav"));
+            let clip = AudioClip::silence(1.0, 1000);
+            let backend: Arc<dyn VoiceBackend + Send + Sync> =
+                Arc::new(moron_voice::PreRecordedBackend::from_clip("vo.wav", clip, &[250]));
+            Ok(backend)
+        });
+        let mut voices = VoiceRouter::new(None, Some(resolver));
+
+        // Both narrations share one backend, so they get consecutive sections.
+        let clips = synthesize_narrations(&mut m, &mut voices, &None).unwrap();
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+        assert!((clips[0].duration() - 0.25).abs() < 1e-9);
+        assert!((clips[1].duration() - 0.75).abs() < 1e-9);
+    }
+
+    #[test]
+    fn recording_section_count_must_match_narrations() {
+        let resolver: VoiceResolver = Arc::new(|_: &Voice| {
+            let clip = AudioClip::silence(1.0, 1000);
+            let backend: Arc<dyn VoiceBackend + Send + Sync> =
+                Arc::new(moron_voice::PreRecordedBackend::from_clip("vo.wav", clip, &[500]));
+            Ok(backend)
+        });
+
+        // One narration for two sections: the second would be dropped.
+        let mut m = M::new();
+        m.voice(Voice::file("vo.wav"));
+        m.narrate("only one");
+        let mut voices = VoiceRouter::new(None, Some(Arc::clone(&resolver)));
+        let err = synthesize_narrations(&mut m, &mut voices, &None).unwrap_err();
+        assert!(err.to_string().contains("2 section(s) but 1 narration(s)"), "got: {err}");
+
+        // Three narrations for two sections.
+        m.narrate("two");
+        m.narrate("three");
+        let mut voices = VoiceRouter::new(None, Some(resolver));
+        let err = synthesize_narrations(&mut m, &mut voices, &None).unwrap_err();
+        assert!(err.to_string().contains("2 section(s) but 3 narration(s)"), "got: {err}");
+    }
+
+    #[test]
+    fn burn_in_follows_build_config() {
+        let mut m = M::new();
+        let mut config = BuildConfig::new("/tmp/output.mp4", "/tmp/index.html");
+        config.caption_config = CaptionConfig::default().with_max_lines(1);
+
+        config.burn_in_captions = true;
+        apply_burn_in(&mut m, &config);
+        assert_eq!(m.burned_in_captions(), Some(&config.caption_config));
+
+        config.burn_in_captions = false;
+        apply_burn_in(&mut m, &config);
+        assert_eq!(m.burned_in_captions(), None);
+    }
+
+    #[test]
+    fn subtitles_skipped_without_narration() {
+        let dir = std::env::temp_dir().join("moron_test_build_subtitles");
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("subtitles.srt");
+        let subtitles = SubtitleConfig::default().with_language("deu");
+
+        let mut m = M::new();
+        m.title("Silent");
+        m.wait(1.0);
+        let track = write_subtitles(&m, &path, &CaptionConfig::default(), &subtitles).unwrap();
+        assert!(track.is_none());
+        assert!(!path.exists());
+
+        m.narrate("Hallo.");
+        let track = write_subtitles(&m, &path, &CaptionConfig::default(), &subtitles)
+            .unwrap()
+            .unwrap();
+        assert_eq!(track.language, "deu");
+        assert!(std::fs::read_to_string(&path).unwrap().contains("Hallo."));
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn voice_router_without_backends_errors() {
+        let mut voices = VoiceRouter::new(None, None);
+        assert!(!voices.is_enabled());
+        assert!(voices.backend_for(Some(&Voice::kokoro())).is_err());
+    }
 }
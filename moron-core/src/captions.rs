@@ -0,0 +1,422 @@
+//! Captions: subtitle cues from narration, exported as SRT and WebVTT.
+//!
+//! Cues are built from the resolved timeline, so they follow TTS timing.
+//! Within a narration, words are grouped into cues using their word timings
+//! when known (see [`M::word_timings`]) or a length-proportional estimate
+//! otherwise. A cue ends at a sentence boundary, or earlier if adding the
+//! next word would break the line-length or duration limits in
+//! [`CaptionConfig`].
+
+use std::ops::Range;
+use std::path::{Path, PathBuf};
+
+use moron_voice::{alignment, WordTiming};
+
+use crate::facade::M;
+use crate::timeline::Segment;
+
+// ---------------------------------------------------------------------------
+// Config
+// ---------------------------------------------------------------------------
+
+/// Rules for splitting narration into caption cues.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CaptionConfig {
+    /// Maximum characters per caption line.
+    pub max_line_chars: usize,
+    /// Maximum lines per cue.
+    pub max_lines: usize,
+    /// Maximum time a single cue stays on screen, in seconds.
+    pub max_duration: f64,
+}
+
+impl Default for CaptionConfig {
+    /// Two lines of up to 42 characters, shown for at most 6 seconds.
+    fn default() -> Self {
+        Self {
+            max_line_chars: 42,
+            max_lines: 2,
+            max_duration: 6.0,
+        }
+    }
+}
+
+impl CaptionConfig {
+    /// Set the maximum characters per line.
+    pub fn with_max_line_chars(mut self, chars: usize) -> Self {
+        self.max_line_chars = chars.max(1);
+        self
+    }
+
+    /// Set the maximum lines per cue.
+    pub fn with_max_lines(mut self, lines: usize) -> Self {
+        self.max_lines = lines.max(1);
+        self
+    }
+
+    /// Set the maximum cue duration in seconds.
+    pub fn with_max_duration(mut self, seconds: f64) -> Self {
+        self.max_duration = seconds;
+        self
+    }
+}
+
+/// Settings for the subtitle track embedded in the video.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SubtitleConfig {
+    /// ISO 639-2 language code of the narration, used to tag the track.
+    pub language: String,
+}
+
+impl Default for SubtitleConfig {
+    /// An English track.
+    fn default() -> Self {
+        Self {
+            language: "eng".to_string(),
+        }
+    }
+}
+
+impl SubtitleConfig {
+    /// Set the ISO 639-2 language code (e.g. `"eng"`, `"deu"`).
+    pub fn with_language(mut self, language: impl Into<String>) -> Self {
+        self.language = language.into();
+        self
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Cues
+// ---------------------------------------------------------------------------
+
+/// One caption shown on screen for a span of time.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CaptionCue {
+    /// Start time in seconds.
+    pub start: f64,
+    /// End time in seconds.
+    pub end: f64,
+    /// The caption lines.
+    pub lines: Vec<String>,
+}
+
+/// Build caption cues for every narration in `m`, in time order.
+pub fn caption_cues(m: &M, config: &CaptionConfig) -> Vec<CaptionCue> {
+    let mut cues: Vec<CaptionCue> = narrations(m)
+        .into_iter()
+        .enumerate()
+        .flat_map(|(i, (text, start, duration))| {
+            split_cues(&narration_words(m, i, text, start, duration), config)
+                .into_iter()
+                .map(|(cue, _)| cue)
+        })
+        .collect();
+    cues.sort_by(|a, b| a.start.total_cmp(&b.start));
+    cues
+}
+
+/// Text, start time and duration of each narration, in timeline order.
+pub(crate) fn narrations(m: &M) -> Vec<(&str, f64, f64)> {
+    let timeline = m.timeline();
+    timeline
+        .segments()
+        .iter()
+        .zip(timeline.segment_starts())
+        .filter_map(|(seg, start)| match seg {
+            Segment::Narration { text, duration, .. } => Some((text.as_str(), start, *duration)),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Absolute word timings of narration `index`, which starts at `start`.
+///
+/// Uses the resolved word timings when present, or spreads the words over
+/// `duration` otherwise.
+pub(crate) fn narration_words(
+    m: &M,
+    index: usize,
+    text: &str,
+    start: f64,
+    duration: f64,
+) -> Vec<WordTiming> {
+    let words = match m.word_timings(index) {
+        Some(words) => words.to_vec(),
+        None => alignment::estimate(text, duration),
+    };
+    words
+        .into_iter()
+        .map(|w| WordTiming {
+            start: start + w.start,
+            end: start + w.end,
+            ..w
+        })
+        .collect()
+}
+
+/// Group one narration's words into cues, each paired with the range of
+/// `words` it covers.
+pub(crate) fn split_cues(
+    words: &[WordTiming],
+    config: &CaptionConfig,
+) -> Vec<(CaptionCue, Range<usize>)> {
+    let mut cues = Vec::new();
+    let mut first = 0;
+    while first < words.len() {
+        let mut last = first;
+        while last + 1 < words.len() && !ends_sentence(&words[last].word) {
+            let next = &words[last + 1];
+            let text = join(&words[first..=last + 1]);
+            let fits = wrap(&text, config.max_line_chars).len() <= config.max_lines;
+            if !fits || next.end - words[first].start > config.max_duration {
+                break;
+            }
+            last += 1;
+        }
+
+        let cue = CaptionCue {
+            start: words[first].start,
+            end: words[last].end,
+            lines: wrap(&join(&words[first..=last]), config.max_line_chars),
+        };
+        cues.push((cue, first..last + 1));
+        first = last + 1;
+    }
+    cues
+}
+
+/// Whether a word closes a sentence.
+fn ends_sentence(word: &str) -> bool {
+    word.trim_end_matches(['"', '\'', ')', '\u{201d}'])
+        .ends_with(['.', '!', '?'])
+}
+
+fn join(words: &[WordTiming]) -> String {
+    words
+        .iter()
+        .map(|w| w.word.as_str())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Greedily wrap `text` into lines of at most `max_chars` characters. A
+/// single word longer than that gets a line of its own.
+pub(crate) fn wrap(text: &str, max_chars: usize) -> Vec<String> {
+    let mut lines: Vec<String> = Vec::new();
+    for word in text.split_whitespace() {
+        match lines.last_mut() {
+            Some(line) if line.chars().count() + 1 + word.chars().count() <= max_chars => {
+                line.push(' ');
+                line.push_str(word);
+            }
+            _ => lines.push(word.to_string()),
+        }
+    }
+    lines
+}
+
+// ---------------------------------------------------------------------------
+// Formats
+// ---------------------------------------------------------------------------
+
+/// Format cues as a SubRip (`.srt`) file.
+pub fn to_srt(cues: &[CaptionCue]) -> String {
+    let mut out = String::new();
+    for (i, cue) in cues.iter().enumerate() {
+        out.push_str(&format!(
+            "{}\n{} --> {}\n{}\n\n",
+            i + 1,
+            timestamp(cue.start, ','),
+            timestamp(cue.end, ','),
+            cue.lines.join("\n"),
+        ));
+    }
+    out
+}
+
+/// Format cues as a WebVTT (`.vtt`) file.
+pub fn to_vtt(cues: &[CaptionCue]) -> String {
+    let mut out = String::from("WEBVTT\n\n");
+    for cue in cues {
+        out.push_str(&format!(
+            "{} --> {}\n{}\n\n",
+            timestamp(cue.start, '.'),
+            timestamp(cue.end, '.'),
+            cue.lines.join("\n"),
+        ));
+    }
+    out
+}
+
+/// `HH:MM:SS` plus milliseconds after `separator`.
+fn timestamp(seconds: f64, separator: char) -> String {
+    let millis = (seconds.max(0.0) * 1000.0).round() as u64;
+    format!(
+        "{:02}:{:02}:{:02}{separator}{:03}",
+        millis / 3_600_000,
+        millis / 60_000 % 60,
+        millis / 1000 % 60,
+        millis % 1000,
+    )
+}
+
+/// Write `.srt` and `.vtt` captions for `m` next to `video_path`, returning
+/// the paths written.
+///
+/// # Errors
+///
+/// Returns an I/O error if either file cannot be written.
+pub fn write_captions(
+    m: &M,
+    video_path: &Path,
+    config: &CaptionConfig,
+) -> Result<Vec<PathBuf>, std::io::Error> {
+    let cues = caption_cues(m, config);
+    let srt = video_path.with_extension("srt");
+    let vtt = video_path.with_extension("vtt");
+    std::fs::write(&srt, to_srt(&cues))?;
+    std::fs::write(&vtt, to_vtt(&cues))?;
+    Ok(vec![srt, vtt])
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Words one second apart, each half a second long.
+    fn timed(text: &str) -> Vec<WordTiming> {
+        text.split_whitespace()
+            .enumerate()
+            .map(|(i, word)| WordTiming {
+                word: word.to_string(),
+                start: i as f64,
+                end: i as f64 + 0.5,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn wrap_greedy() {
+        assert_eq!(wrap("the quick brown fox", 9), vec!["the quick", "brown fox"]);
+        assert_eq!(wrap("supercalifragilistic is long", 8), vec![
+            "supercalifragilistic",
+            "is long"
+        ]);
+        assert!(wrap("", 10).is_empty());
+    }
+
+    #[test]
+    fn timestamps() {
+        assert_eq!(timestamp(0.0, ','), "00:00:00,000");
+        assert_eq!(timestamp(3723.4567, '.'), "01:02:03.457");
+    }
+
+    #[test]
+    fn cues_break_at_sentences() {
+        let (cues, ranges): (Vec<_>, Vec<_>) =
+            split_cues(&timed("Hello there. How are you?"), &CaptionConfig::default())
+                .into_iter()
+                .unzip();
+        assert_eq!(cues.len(), 2);
+        assert_eq!(ranges, vec![0..2, 2..5]);
+        assert_eq!(cues[0].lines, vec!["Hello there."]);
+        assert_eq!(cues[0].start, 0.0);
+        assert_eq!(cues[0].end, 1.5);
+        assert_eq!(cues[1].lines, vec!["How are you?"]);
+        assert_eq!(cues[1].start, 2.0);
+    }
+
+    #[test]
+    fn cues_respect_line_limits() {
+        let config = CaptionConfig::default()
+            .with_max_line_chars(10)
+            .with_max_lines(1);
+        let cues = split_cues(&timed("one two three four five"), &config);
+        let lines: Vec<_> = cues.iter().map(|(c, _)| c.lines.join("|")).collect();
+        assert_eq!(lines, vec!["one two", "three four", "five"]);
+    }
+
+    #[test]
+    fn cues_respect_max_duration() {
+        let config = CaptionConfig::default().with_max_duration(2.0);
+        let cues = split_cues(&timed("a b c d e"), &config);
+        // "a b c" would end at 2.5s, past the 2s limit.
+        assert_eq!(cues[0].0.lines, vec!["a b"]);
+        assert!(cues.iter().all(|(c, _)| c.end - c.start <= 2.0));
+    }
+
+    #[test]
+    fn cues_follow_timeline() {
+        let mut m = M::new();
+        m.wait(1.0);
+        m.narrate("Welcome to moron.");
+        m.title("Hi");
+        m.narrate("Bye.");
+
+        let cues = caption_cues(&m, &CaptionConfig::default());
+        assert_eq!(cues.len(), 2);
+        assert_eq!(cues[0].start, 1.0);
+        // "Welcome to moron." is estimated at 3 words * 0.4s.
+        assert!((cues[0].end - 2.2).abs() < 1e-9);
+        assert!((cues[1].start - 2.2).abs() < 1e-9);
+        assert_eq!(cues[1].lines, vec!["Bye."]);
+    }
+
+    #[test]
+    fn cues_use_resolved_word_timings() {
+        let mut m = M::new();
+        m.narrate("one two");
+        m.resolve_narration_durations(&[5.0]).unwrap();
+        m.resolve_word_timings(vec![timed("one two")]).unwrap();
+
+        let cues = caption_cues(&m, &CaptionConfig::default());
+        assert_eq!(cues[0].end, 1.5);
+    }
+
+    #[test]
+    fn srt_and_vtt_format() {
+        let cues = vec![
+            CaptionCue {
+                start: 0.0,
+                end: 1.25,
+                lines: vec!["Hello".into(), "world".into()],
+            },
+            CaptionCue {
+                start: 61.5,
+                end: 62.0,
+                lines: vec!["Bye".into()],
+            },
+        ];
+
+        assert_eq!(
+            to_srt(&cues),
+            "1\n00:00:00,000 --> 00:00:01,250\nHello\nworld\n\n\
+             2\n00:01:01,500 --> 00:01:02,000\nBye\n\n"
+        );
+        assert_eq!(
+            to_vtt(&cues),
+            "WEBVTT\n\n\
+             00:00:00.000 --> 00:00:01.250\nHello\nworld\n\n\
+             00:01:01.500 --> 00:01:02.000\nBye\n\n"
+        );
+    }
+
+    #[test]
+    fn write_captions_next_to_video() {
+        let dir = std::env::temp_dir().join("moron_test_write_captions");
+        std::fs::create_dir_all(&dir).unwrap();
+        let mut m = M::new();
+        m.narrate("Hello.");
+
+        let paths = write_captions(&m, &dir.join("out.mp4"), &CaptionConfig::default()).unwrap();
+        assert_eq!(paths, vec![dir.join("out.srt"), dir.join("out.vtt")]);
+        let srt = std::fs::read_to_string(&paths[0]).unwrap();
+        assert!(srt.starts_with("1\n00:00:00,000 --> 00:00:00,400\nHello."));
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
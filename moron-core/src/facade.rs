@@ -4,10 +4,15 @@
 //! machinery (Bevy ECS, renderer, timeline, FFmpeg, TTS) behind a clean,
 //! sequential API. Scenes implement the `Scene` trait and receive `&mut M`.
 
+use std::path::PathBuf;
+
 use moron_themes::Theme;
-use moron_voice::Voice;
+use moron_voice::{alignment, Voice, WordTiming};
 
-use crate::frame::ElementKind;
+use crate::captions::CaptionConfig;
+use crate::ffmpeg::FfmpegError;
+use crate::frame::{ElementKind, Pane};
+use crate::soundtrack::{Music, Sfx};
 use crate::timeline::{Segment, Timeline};
 
 /// Default duration for `m.beat()` — a short rhythmic pause.
@@ -31,6 +36,17 @@ pub enum Direction {
     Neutral,
 }
 
+impl Direction {
+    /// The string form used in `FrameState` ("up", "down", or "neutral").
+    pub(crate) fn as_str(self) -> &'static str {
+        match self {
+            Direction::Up => "up",
+            Direction::Down => "down",
+            Direction::Neutral => "neutral",
+        }
+    }
+}
+
 /// Opaque handle to a visual element on the timeline.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct Element(pub(crate) u64);
@@ -46,17 +62,23 @@ pub(crate) struct ElementRecord {
     pub content: String,
     /// List items (non-empty only for Steps elements).
     pub items: Vec<String>,
+    /// Comparison panes (non-empty only for Compare elements).
+    pub panes: Vec<Pane>,
     /// Timeline position (in seconds) when this element was created.
     pub created_at: f64,
-    /// Number of timeline segments that existed when this element was created.
+    /// Number of timeline segments preceding this element's creation point.
     /// Used to recompute `created_at` after narration durations are resolved.
     pub segments_at_creation: usize,
     /// Timeline position (in seconds) when this element was cleared from the screen.
     /// `None` means the element stays visible until the end of the timeline.
     pub ended_at: Option<f64>,
-    /// Number of timeline segments that existed when this element was ended.
+    /// Number of timeline segments preceding the point where this element ended.
     /// Used to recompute `ended_at` after narration durations are resolved.
     pub segments_at_end: Option<usize>,
+    /// Index into `timeline.segments()` of this element's exit animation.
+    /// When set, `ended_at` is the end of that segment so the element stays
+    /// visible while the exit technique plays.
+    pub exit_segment: Option<usize>,
 }
 
 /// Internal record of an animation, binding a technique to its target elements.
@@ -71,6 +93,93 @@ pub(crate) struct AnimationRecord {
     /// Index into `timeline.segments()` for this animation's segment.
     /// Used to compute absolute time window (survives duration resolution).
     pub segment_index: usize,
+    /// Whether this is an exit animation recorded by [`M::hide_with`].
+    /// Exit animations only take effect once their window has started.
+    pub exit: bool,
+    /// Whether this is an emphasis recorded by [`M::focus_with`]. Like exits,
+    /// emphasis only takes effect once its window has started, so it cannot
+    /// override an entrance that is still running.
+    pub emphasis: bool,
+}
+
+/// Internal record of a slide transition recorded by [`M::clear_with`].
+///
+/// The outgoing set is fixed when the transition is recorded. The incoming
+/// set is every element created right after the transition segment, so it
+/// is looked up at frame time.
+pub(crate) struct TransitionRecord {
+    /// The boxed transition object — called via `outgoing`/`incoming` at frame time.
+    pub transition: Box<dyn moron_techniques::Transition>,
+    /// Element IDs that were on screen when the transition started.
+    pub outgoing_ids: Vec<u64>,
+    /// Index into `timeline.segments()` for this transition's segment.
+    pub segment_index: usize,
+}
+
+/// Internal record of a focus recorded by [`M::focus`].
+///
+/// The focus is active for as long as its annotation element is visible.
+pub(crate) struct FocusRecord {
+    /// The element being drawn attention to.
+    pub target_id: u64,
+    /// The annotation element created for the callout text.
+    pub annotation_id: u64,
+    /// The highlight driving the ring intensity and dimming of other elements.
+    pub highlight: moron_techniques::Highlight,
+    /// Index into `timeline.segments()` for the highlight's ramp-in segment.
+    pub segment_index: usize,
+}
+
+/// Internal record of a theme change recorded by [`M::theme`] or
+/// [`M::theme_fade`].
+pub(crate) struct ThemeRecord {
+    /// The theme that takes effect.
+    pub theme: Theme,
+    /// Timeline time (seconds) at which the change starts.
+    pub changed_at: f64,
+    /// Number of timeline segments that existed at the change, used to
+    /// recompute `changed_at` when narration durations are resolved.
+    pub segments_at_change: usize,
+    /// Seconds over which colors blend from the previous theme (0 = instant).
+    pub fade: f64,
+}
+
+/// Internal record of background music started by [`M::music`] or
+/// [`M::music_with`].
+pub(crate) struct MusicRecord {
+    /// What to play and how.
+    pub music: Music,
+    /// Timeline time (seconds) at which the music starts.
+    pub started_at: f64,
+    /// Number of timeline segments that existed at the start, used to
+    /// recompute `started_at` when narration durations are resolved.
+    pub segments_at_start: usize,
+}
+
+/// Internal record of a sound effect cued by [`M::sfx`], [`M::sfx_with`]
+/// or [`M::play_with_sfx`].
+pub(crate) struct SfxRecord {
+    /// What to play.
+    pub sfx: Sfx,
+    /// Timeline time (seconds) at which the sound starts.
+    pub cued_at: f64,
+    /// Number of timeline segments that existed at the cue, used to
+    /// recompute `cued_at` when narration durations are resolved.
+    pub segments_at_cue: usize,
+}
+
+/// Internal record of an animation anchored to a spoken word by
+/// [`M::at_word`] or [`M::at_word_on`].
+///
+/// The animation segment's offset into its narration is recomputed whenever
+/// narration durations or word timings are resolved.
+struct WordCueRecord {
+    /// Index into `timeline.segments()` of the animation segment.
+    segment_index: usize,
+    /// Index into `timeline.segments()` of the narration it is anchored to.
+    narration_index: usize,
+    /// Zero-based index of the word within the narration text.
+    word: usize,
 }
 
 // ---------------------------------------------------------------------------
@@ -104,6 +213,55 @@ impl std::fmt::Display for ResolveDurationError {
 
 impl std::error::Error for ResolveDurationError {}
 
+// ---------------------------------------------------------------------------
+// ElementError
+// ---------------------------------------------------------------------------
+
+/// Error returned when a facade method can't use an [`Element`] handle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ElementError {
+    /// The handle was not minted by this `M` instance.
+    Unknown(Element),
+    /// Other segments were recorded after the element's entrance, so
+    /// [`M::narrate_over`] can no longer overlap it.
+    EntranceNotLatest(Element),
+    /// The element was created after the narration [`M::at_word`] anchors
+    /// to, so it can't be animated while that narration plays.
+    CreatedAfterNarration(Element),
+    /// Other segments were recorded after the most recent narration, so
+    /// [`M::at_word`] can no longer anchor to it.
+    NarrationNotLatest,
+}
+
+impl std::fmt::Display for ElementError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Unknown(element) => {
+                write!(f, "unknown element handle: {}", element.0)
+            }
+            Self::EntranceNotLatest(element) => {
+                write!(
+                    f,
+                    "element {} has segments recorded after its entrance",
+                    element.0
+                )
+            }
+            Self::CreatedAfterNarration(element) => {
+                write!(
+                    f,
+                    "element {} was created after the narration it is anchored to",
+                    element.0
+                )
+            }
+            Self::NarrationNotLatest => {
+                write!(f, "segments were recorded after the narration")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ElementError {}
+
 // ---------------------------------------------------------------------------
 // Scene trait
 // ---------------------------------------------------------------------------
@@ -131,12 +289,35 @@ pub struct M {
     current_theme: Theme,
     /// Active voice/TTS configuration.
     current_voice: Voice,
+    /// Whether [`voice`](Self::voice) has been called. Until then narrations
+    /// carry no voice and are left to the build's default backend.
+    voice_chosen: bool,
     /// The timeline recording all segments produced during scene building.
     timeline: Timeline,
     /// Registry of all minted elements with their metadata.
     elements: Vec<ElementRecord>,
     /// Registry of all animation records (technique + target + segment index).
     animations: Vec<AnimationRecord>,
+    /// Registry of slide transitions recorded by `clear_with`.
+    transitions: Vec<TransitionRecord>,
+    /// Registry of focus highlights recorded by `focus`.
+    focuses: Vec<FocusRecord>,
+    /// Theme changes in the order they were recorded.
+    themes: Vec<ThemeRecord>,
+    /// Background music in the order it was started.
+    music: Vec<MusicRecord>,
+    /// Sound effects in the order they were cued.
+    sfx: Vec<SfxRecord>,
+    /// Word timings per narration, in timeline order. Empty until resolved.
+    word_timings: Vec<Vec<WordTiming>>,
+    /// Animations anchored to spoken words.
+    word_cues: Vec<WordCueRecord>,
+    /// Rules for captions burned into rendered frames, or `None` to leave
+    /// them out.
+    burned_in_captions: Option<CaptionConfig>,
+    /// Index of the first segment of the open [`together`](Self::together)
+    /// block, if one is being recorded.
+    parallel_block: Option<usize>,
 }
 
 impl M {
@@ -146,13 +327,23 @@ impl M {
             next_element_id: 0,
             current_theme: Theme::default(),
             current_voice: Voice::kokoro(),
+            voice_chosen: false,
             timeline: Timeline::default(),
             elements: Vec::new(),
             animations: Vec::new(),
+            transitions: Vec::new(),
+            focuses: Vec::new(),
+            themes: Vec::new(),
+            music: Vec::new(),
+            sfx: Vec::new(),
+            word_timings: Vec::new(),
+            word_cues: Vec::new(),
+            burned_in_captions: None,
+            parallel_block: None,
         }
     }
 
-    /// Get the current theme.
+    /// Get the most recently set theme.
     pub fn current_theme(&self) -> &Theme {
         &self.current_theme
     }
@@ -173,14 +364,69 @@ impl M {
     ///
     /// Duration is estimated from word count at [`DEFAULT_NARRATION_WPM`].
     pub fn narrate(&mut self, text: &str) {
-        let words = text.split_whitespace().count().max(1) as f64;
-        let duration = words * 60.0 / DEFAULT_NARRATION_WPM;
-        self.timeline.add_segment(Segment::Narration {
+        self.push_segment(Segment::Narration {
             text: text.to_string(),
-            duration,
+            duration: estimate_narration_duration(text),
+            voice: self.narration_voice(),
         });
     }
 
+    /// Narrate while `element` makes its entrance.
+    ///
+    /// The narration starts together with the first animation that targets
+    /// `element` instead of after it, so speech and motion overlap. Anything
+    /// recorded afterwards waits for whichever of the two finishes last. If
+    /// the element has not been animated, the narration starts at the first
+    /// segment recorded since the element appeared, or at the current
+    /// position if there is none. Inside a [`together`](Self::together)
+    /// block the narration starts with the block, like everything else in it.
+    ///
+    /// The entrance must be what was recorded last: only segments that play
+    /// alongside it or animate `element` may follow it, and none of them may
+    /// be narration. Otherwise the narration would move back in time and
+    /// talk over whatever came after the entrance.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ElementError::Unknown`] if `element` was not created by this
+    /// `M`, or [`ElementError::EntranceNotLatest`] if other segments were
+    /// recorded since its entrance. Nothing is recorded in either case.
+    pub fn narrate_over(&mut self, element: Element, text: &str) -> Result<(), ElementError> {
+        let id = self.element_id(element)?;
+        let segment = Segment::Narration {
+            text: text.to_string(),
+            duration: estimate_narration_duration(text),
+            voice: self.narration_voice(),
+        };
+        if self.parallel_block.is_some() {
+            self.push_segment(segment);
+            return Ok(());
+        }
+
+        let segment_count = self.timeline.segments().len();
+        let anchor = self
+            .animations
+            .iter()
+            .find(|rec| rec.target_ids.contains(&id))
+            .map(|rec| rec.segment_index)
+            .or_else(|| {
+                self.elements
+                    .iter()
+                    .find(|rec| rec.id == id)
+                    .map(|rec| rec.segments_at_creation)
+                    .filter(|&idx| idx < segment_count)
+            });
+
+        match anchor {
+            Some(idx) if !self.is_latest_entrance(idx, id) => {
+                return Err(ElementError::EntranceNotLatest(element));
+            }
+            Some(idx) => self.timeline.add_segment_with(segment, idx),
+            None => self.timeline.add_segment(segment),
+        }
+        Ok(())
+    }
+
     /// Display text on screen in a context-aware manner.
     pub fn show(&mut self, text: &str) -> Element {
         self.mint_element_with_meta(ElementKind::Show, text.to_string(), Vec::new())
@@ -200,14 +446,9 @@ impl M {
 
     /// Display a metric with a directional indicator.
     pub fn metric(&mut self, label: &str, value: &str, direction: Direction) -> Element {
-        let dir_str = match direction {
-            Direction::Up => "up",
-            Direction::Down => "down",
-            Direction::Neutral => "neutral",
-        };
         self.mint_element_with_meta(
             ElementKind::Metric {
-                direction: dir_str.to_string(),
+                direction: direction.as_str().to_string(),
             },
             format!("{label}: {value}"),
             Vec::new(),
@@ -226,24 +467,110 @@ impl M {
         )
     }
 
+    /// Display a side-by-side comparison of two labelled panes.
+    ///
+    /// `before` is shown on the left and `after` on the right. Techniques
+    /// played on the element animate each pane separately, so a `Stagger`
+    /// reveals the left pane first.
+    pub fn compare(&mut self, before: Pane, after: Pane) -> Element {
+        let content = format!("{} vs {}", before.label, after.label);
+        let el = self.mint_element_with_meta(ElementKind::Compare, content, Vec::new());
+        if let Some(rec) = self.elements.last_mut() {
+            rec.panes = vec![before, after];
+        }
+        el
+    }
+
+    /// Display a custom visual rendered by a named UI component.
+    ///
+    /// `name` selects a component registered on the React side with
+    /// `registerComponent`, and `props` is handed to it unchanged. This is
+    /// the extension point for visuals the built-in element kinds don't cover.
+    pub fn diagram(&mut self, name: &str, props: serde_json::Value) -> Element {
+        self.mint_element_with_meta(
+            ElementKind::Component {
+                name: name.to_string(),
+                props,
+            },
+            name.to_string(),
+            Vec::new(),
+        )
+    }
+
     // -- Pacing ------------------------------------------------------------
 
     /// Insert a short rhythmic pause ([`BEAT_DURATION`] seconds).
     pub fn beat(&mut self) {
-        self.timeline
-            .add_segment(Segment::Silence { duration: BEAT_DURATION });
+        self.push_segment(Segment::Silence { duration: BEAT_DURATION });
     }
 
     /// Insert a slightly longer breathing pause ([`BREATH_DURATION`] seconds).
     pub fn breath(&mut self) {
-        self.timeline
-            .add_segment(Segment::Silence { duration: BREATH_DURATION });
+        self.push_segment(Segment::Silence { duration: BREATH_DURATION });
     }
 
     /// Wait for an explicit duration in seconds.
     pub fn wait(&mut self, duration: f64) {
-        self.timeline
-            .add_segment(Segment::Silence { duration });
+        self.push_segment(Segment::Silence { duration });
+    }
+
+    // -- Media -------------------------------------------------------------
+
+    /// Play a video clip full-frame, with its audio, for its whole length.
+    ///
+    /// The clip's duration is probed with `ffprobe` up front, so later
+    /// segments are timed after it.
+    /// During the clip the rendered frame is replaced by the clip's frames,
+    /// letterboxed to the output resolution. Narration recorded with
+    /// [`together`](Self::together) or [`narrate_over`](Self::narrate_over)
+    /// plays on top of the clip's own audio.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`FfmpegError`] if the file is missing or cannot be probed.
+    pub fn clip(&mut self, path: impl Into<PathBuf>) -> Result<(), FfmpegError> {
+        let path = path.into();
+        let duration = crate::ffmpeg::probe_duration(&path)?;
+        self.push_segment(Segment::Clip { path, duration });
+        Ok(())
+    }
+
+    /// Start background music at the current timeline position.
+    ///
+    /// Plays the file once at full volume, ducked under narration. See
+    /// [`music_with`](Self::music_with) for volume, looping and fades.
+    pub fn music(&mut self, path: impl Into<PathBuf>) {
+        self.music_with(Music::new(path));
+    }
+
+    /// Start background music at the current timeline position with the
+    /// given settings.
+    ///
+    /// Music sits on its own track and doesn't add time to the timeline. It
+    /// plays until the next call to `music` or the end of the video, and is
+    /// lowered while narration plays unless ducking is turned off.
+    pub fn music_with(&mut self, music: Music) {
+        let seg_count = self.cursor();
+        self.music.push(MusicRecord {
+            music,
+            started_at: self.timeline.time_after(seg_count),
+            segments_at_start: seg_count,
+        });
+    }
+
+    /// Play a sound effect at the current timeline position.
+    ///
+    /// The sound is mixed over everything else and doesn't add time to the
+    /// timeline, so it can be cued alongside whatever comes next.
+    pub fn sfx(&mut self, path: impl Into<PathBuf>) {
+        self.sfx_with(Sfx::new(path));
+    }
+
+    /// Play a sound effect with the given settings at the current timeline
+    /// position.
+    pub fn sfx_with(&mut self, sfx: Sfx) {
+        let seg_count = self.cursor();
+        self.cue_sfx(sfx, seg_count);
     }
 
     // -- Scene management --------------------------------------------------
@@ -254,8 +581,8 @@ impl M {
     /// current timeline position, so they become invisible from this point on.
     /// Call this between logical "slides" to get a clean screen.
     pub fn clear(&mut self) {
-        let now = self.timeline.total_duration();
-        let seg_count = self.timeline.segments().len();
+        let seg_count = self.cursor();
+        let now = self.timeline.time_after(seg_count);
         for rec in &mut self.elements {
             if rec.ended_at.is_none() {
                 rec.ended_at = Some(now);
@@ -264,6 +591,173 @@ impl M {
         }
     }
 
+    /// Clear all visible elements with an animated transition.
+    ///
+    /// Records an [`Animation`](Segment::Animation) segment for `transition`.
+    /// Elements on screen stay visible until it ends, transformed by the
+    /// transition's outgoing output. Elements that appear by the time it
+    /// ends (created immediately afterwards, before any further narration,
+    /// animation, or pause, or alongside it in a [`together`](Self::together)
+    /// block) form the incoming set: they appear at the start of the
+    /// transition and are transformed by its incoming output.
+    pub fn clear_with(&mut self, transition: impl moron_techniques::Transition + 'static) {
+        let segment_index = self.timeline.segments().len();
+        self.push_segment(Segment::Animation {
+            name: transition.name().to_string(),
+            duration: transition.duration(),
+        });
+
+        let ended_at = exit_end(&self.timeline, &self.timeline.segment_starts(), segment_index);
+        let mut outgoing_ids = Vec::new();
+        for rec in &mut self.elements {
+            if rec.ended_at.is_none() {
+                rec.ended_at = Some(ended_at);
+                rec.segments_at_end = Some(segment_index + 1);
+                rec.exit_segment = Some(segment_index);
+                outgoing_ids.push(rec.id);
+            }
+        }
+
+        self.transitions.push(TransitionRecord {
+            transition: Box::new(transition),
+            outgoing_ids,
+            segment_index,
+        });
+    }
+
+    /// Draw attention to `element` with a callout annotation.
+    ///
+    /// Creates an annotation element linked to `element` and plays a
+    /// [`Highlight`](moron_techniques::Highlight) on it: the target pulses,
+    /// a highlight ring fades in, and every other visible element dims. The
+    /// focus lasts until the returned annotation leaves the screen (via
+    /// [`hide`](Self::hide), [`clear`](Self::clear), or a transition).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ElementError::Unknown`] if `element` was not created by this
+    /// `M`. Nothing is recorded in that case.
+    pub fn focus(&mut self, element: Element, annotation: &str) -> Result<Element, ElementError> {
+        self.focus_with(element, annotation, moron_techniques::Highlight::default())
+    }
+
+    /// Like [`focus`](Self::focus), with a custom highlight duration, dim level, or pulse.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ElementError::Unknown`] if `element` was not created by this
+    /// `M`. Nothing is recorded in that case.
+    pub fn focus_with(
+        &mut self,
+        element: Element,
+        annotation: &str,
+        highlight: moron_techniques::Highlight,
+    ) -> Result<Element, ElementError> {
+        let target_id = self.element_id(element)?;
+        let note = self.mint_element_with_meta(
+            ElementKind::Annotation { target: target_id },
+            annotation.to_string(),
+            Vec::new(),
+        );
+
+        let segment_index = self.timeline.segments().len();
+        self.record_animation(Box::new(highlight.clone()), vec![target_id]);
+        if let Some(anim) = self.animations.last_mut() {
+            anim.emphasis = true;
+        }
+        self.focuses.push(FocusRecord {
+            target_id,
+            annotation_id: note.0,
+            highlight,
+            segment_index,
+        });
+        Ok(note)
+    }
+
+    /// Remove a single element from the screen immediately.
+    ///
+    /// Like [`clear`](Self::clear), but only for `element`. Hiding an element
+    /// that has already left the screen has no effect.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ElementError::Unknown`] if `element` was not created by this `M`.
+    pub fn hide(&mut self, element: Element) -> Result<(), ElementError> {
+        let id = self.element_id(element)?;
+        let seg_count = self.cursor();
+        let now = self.timeline.time_after(seg_count);
+        if let Some(rec) = self.elements.iter_mut().find(|rec| rec.id == id)
+            && rec.ended_at.is_none()
+        {
+            rec.ended_at = Some(now);
+            rec.segments_at_end = Some(seg_count);
+        }
+        Ok(())
+    }
+
+    /// Remove a single element from the screen with an exit technique.
+    ///
+    /// Records an [`Animation`](Segment::Animation) segment for `technique`.
+    /// Exit techniques such as `FadeOut` or `SlideOut` play forwards; any
+    /// other technique plays in reverse, so `FadeUp` sinks the element back
+    /// down as it fades. The element stays visible until the segment ends.
+    /// Its exit is evaluated after all other animations, so it takes over
+    /// from whatever state the element was in.
+    /// Hiding an element that has already left the screen has no effect.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ElementError::Unknown`] if `element` was not created by this
+    /// `M`. Nothing is recorded in that case.
+    pub fn hide_with(
+        &mut self,
+        element: Element,
+        technique: impl moron_techniques::Technique + 'static,
+    ) -> Result<(), ElementError> {
+        let id = self.element_id(element)?;
+        if self.elements.iter().any(|rec| rec.id == id && rec.ended_at.is_some()) {
+            return Ok(());
+        }
+
+        let segment_index = self.timeline.segments().len();
+        self.record_animation(Box::new(technique), vec![id]);
+        if let Some(anim) = self.animations.last_mut() {
+            anim.exit = true;
+        }
+
+        let ended_at = exit_end(&self.timeline, &self.timeline.segment_starts(), segment_index);
+        if let Some(rec) = self.elements.iter_mut().find(|rec| rec.id == id) {
+            rec.ended_at = Some(ended_at);
+            rec.segments_at_end = Some(segment_index + 1);
+            rec.exit_segment = Some(segment_index);
+        }
+        Ok(())
+    }
+
+    /// Record several segments that share one time window.
+    ///
+    /// Every narration, animation, and pause recorded inside `f` starts at
+    /// the same instant instead of one after the other. The block lasts as
+    /// long as its longest segment, and elements created inside it appear
+    /// at the block's start. Nested blocks join the outer one.
+    ///
+    /// ```ignore
+    /// m.together(|m| {
+    ///     m.narrate("Two things happen at once.");
+    ///     m.play_on(title, FadeIn::default()).unwrap();
+    ///     m.play_on(subtitle, FadeUp::default()).unwrap();
+    /// });
+    /// ```
+    pub fn together(&mut self, f: impl FnOnce(&mut M)) {
+        if self.parallel_block.is_some() {
+            f(self);
+            return;
+        }
+        self.parallel_block = Some(self.timeline.segments().len());
+        f(self);
+        self.parallel_block = None;
+    }
+
     // -- Techniques --------------------------------------------------------
 
     /// Execute a composable animation technique.
@@ -272,37 +766,181 @@ impl M {
     /// stores the technique object for frame-time evaluation. The animation
     /// targets the most recently created element.
     pub fn play(&mut self, technique: impl moron_techniques::Technique + 'static) {
-        let segment_index = self.timeline.segments().len();
         let target_ids = self
             .elements
             .last()
             .map(|e| vec![e.id])
             .unwrap_or_default();
-        self.timeline.add_segment(Segment::Animation {
-            name: technique.name().to_string(),
-            duration: technique.duration(),
-        });
-        self.animations.push(AnimationRecord {
-            technique: Box::new(technique),
-            target_ids,
-            segment_index,
-        });
+        self.record_animation(Box::new(technique), target_ids);
+    }
+
+    /// Execute a technique on a specific element.
+    ///
+    /// Like [`play`](Self::play), but targets `element` instead of the most
+    /// recently created element.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ElementError::Unknown`] if `element` was not created by this
+    /// `M`. Nothing is recorded in that case.
+    pub fn play_on(
+        &mut self,
+        element: Element,
+        technique: impl moron_techniques::Technique + 'static,
+    ) -> Result<(), ElementError> {
+        self.play_on_all(&[element], technique)
+    }
+
+    /// Execute a technique on several elements at once.
+    ///
+    /// All targets animate over the same timeline segment. An empty slice
+    /// records the segment without animating anything, which is useful for
+    /// holding the timeline for exactly the technique's duration.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ElementError::Unknown`] for the first handle that was not
+    /// created by this `M`. Nothing is recorded in that case.
+    pub fn play_on_all(
+        &mut self,
+        elements: &[Element],
+        technique: impl moron_techniques::Technique + 'static,
+    ) -> Result<(), ElementError> {
+        let target_ids = elements
+            .iter()
+            .map(|&e| self.element_id(e))
+            .collect::<Result<Vec<_>, _>>()?;
+        self.record_animation(Box::new(technique), target_ids);
+        Ok(())
+    }
+
+    /// Execute a technique when the narrator says a given word.
+    ///
+    /// Targets the most recently created element, like [`play`](Self::play),
+    /// but starts as word `word` (zero-based) of the most recent narration is
+    /// spoken, overlapping the narration instead of following it. While
+    /// recording, the start is estimated from word lengths; once the build
+    /// resolves TTS durations and word timings it lands on the spoken word.
+    /// A `word` past the end of the narration starts at its end. Without any
+    /// narration this behaves like [`play`](Self::play).
+    ///
+    /// Call it right after the narration: only other word-anchored
+    /// animations may be recorded in between.
+    ///
+    /// ```ignore
+    /// let bullet = m.show("Speed");
+    /// m.narrate("The key is speed.");
+    /// m.at_word(3, FadeIn::default())?; // reveal on "speed."
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ElementError::NarrationNotLatest`] if other segments were
+    /// recorded after the narration, or
+    /// [`ElementError::CreatedAfterNarration`] if the target was created
+    /// after it. Nothing is recorded in either case.
+    pub fn at_word(
+        &mut self,
+        word: usize,
+        technique: impl moron_techniques::Technique + 'static,
+    ) -> Result<(), ElementError> {
+        let target_ids = self
+            .elements
+            .last()
+            .map(|e| vec![e.id])
+            .unwrap_or_default();
+        self.record_word_animation(Box::new(technique), target_ids, word)
+    }
+
+    /// Execute a technique on a specific element when the narrator says a
+    /// given word.
+    ///
+    /// Like [`at_word`](Self::at_word), but targets `element` instead of the
+    /// most recently created element.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ElementError::Unknown`] if `element` was not created by this
+    /// `M`, and the errors of [`at_word`](Self::at_word) otherwise. Nothing
+    /// is recorded in that case.
+    pub fn at_word_on(
+        &mut self,
+        element: Element,
+        word: usize,
+        technique: impl moron_techniques::Technique + 'static,
+    ) -> Result<(), ElementError> {
+        let id = self.element_id(element)?;
+        self.record_word_animation(Box::new(technique), vec![id], word)
+    }
+
+    /// Execute a technique like [`play`](Self::play), with a sound effect
+    /// starting together with the animation.
+    pub fn play_with_sfx(
+        &mut self,
+        technique: impl moron_techniques::Technique + 'static,
+        sfx: Sfx,
+    ) {
+        let seg_count = self.cursor();
+        self.play(technique);
+        self.cue_sfx(sfx, seg_count);
     }
 
     // -- Configuration -----------------------------------------------------
 
-    /// Set the active theme.
+    /// Switch to `theme` at the current timeline position.
+    ///
+    /// Frames before this point keep the previous theme, so a scene can move
+    /// from dark to light partway through.
     pub fn theme(&mut self, theme: Theme) {
+        self.theme_fade(theme, 0.0);
+    }
+
+    /// Switch to `theme` at the current timeline position, blending colors
+    /// from the previous theme over `duration` seconds.
+    ///
+    /// The fade runs alongside whatever comes next; it doesn't add time to
+    /// the timeline. See [`Theme::blend`] for how tokens are interpolated.
+    pub fn theme_fade(&mut self, theme: Theme, duration: f64) {
+        let seg_count = self.cursor();
+        self.themes.push(ThemeRecord {
+            theme: theme.clone(),
+            changed_at: self.timeline.time_after(seg_count),
+            segments_at_change: seg_count,
+            fade: duration.max(0.0),
+        });
         self.current_theme = theme;
     }
 
-    /// Set the active TTS voice.
+    /// Set the TTS voice for narration recorded from here on.
+    ///
+    /// Each narration keeps the voice that was active when it was recorded,
+    /// so a scene can alternate narrators between sections. Narration
+    /// recorded before the first call carries no voice, which leaves the
+    /// choice to the build (see [`BuildConfig::voice_backend`]).
+    ///
+    /// [`BuildConfig::voice_backend`]: crate::build::BuildConfig::voice_backend
     pub fn voice(&mut self, voice: Voice) {
         self.current_voice = voice;
+        self.voice_chosen = true;
     }
 
     // -- Accessors (crate-internal) ----------------------------------------
 
+    /// Get the recorded theme changes (for frame state computation).
+    pub(crate) fn themes(&self) -> &[ThemeRecord] {
+        &self.themes
+    }
+
+    /// Get the recorded background music (for audio assembly).
+    pub(crate) fn music_records(&self) -> &[MusicRecord] {
+        &self.music
+    }
+
+    /// Get the cued sound effects (for audio assembly).
+    pub(crate) fn sfx_records(&self) -> &[SfxRecord] {
+        &self.sfx
+    }
+
     /// Get the element metadata records (for frame state computation).
     pub(crate) fn elements(&self) -> &[ElementRecord] {
         &self.elements
@@ -313,6 +951,16 @@ impl M {
         &self.animations
     }
 
+    /// Get the focus records (for frame state computation).
+    pub(crate) fn focuses(&self) -> &[FocusRecord] {
+        &self.focuses
+    }
+
+    /// Get the transition records (for frame state computation).
+    pub(crate) fn transitions(&self) -> &[TransitionRecord] {
+        &self.transitions
+    }
+
     // -- Duration resolution -----------------------------------------------
 
     /// Return the number of narration segments in the timeline.
@@ -348,60 +996,298 @@ impl M {
             self.timeline.update_segment_duration(idx, dur);
         }
 
-        // Recompute element created_at and ended_at timestamps.
-        for rec in &mut self.elements {
-            rec.created_at = self.timeline.segments()[..rec.segments_at_creation]
-                .iter()
-                .map(|s| s.duration())
-                .sum();
-            if let Some(seg_count) = rec.segments_at_end {
-                rec.ended_at = Some(
-                    self.timeline.segments()[..seg_count]
-                        .iter()
-                        .map(|s| s.duration())
-                        .sum(),
-                );
-            }
-        }
+        self.retime_records();
+        Ok(())
+    }
 
+    /// Record when each word of each narration is spoken.
+    ///
+    /// `timings` must contain exactly one entry per narration segment, in
+    /// timeline order, with times relative to the start of that narration.
+    /// Animations recorded with [`at_word`](Self::at_word) move onto their
+    /// words. Calling this again replaces the previous timings.
+    pub fn resolve_word_timings(
+        &mut self,
+        timings: Vec<Vec<WordTiming>>,
+    ) -> Result<(), ResolveDurationError> {
+        let expected = self.narration_count();
+        if timings.len() != expected {
+            return Err(ResolveDurationError::LengthMismatch {
+                expected,
+                provided: timings.len(),
+            });
+        }
+        self.word_timings = timings;
+        self.retime_records();
         Ok(())
     }
 
-    // -- Internal helpers --------------------------------------------------
+    /// Word timings for the `narration`-th narration segment, relative to its
+    /// start, or `None` if they haven't been resolved.
+    pub fn word_timings(&self, narration: usize) -> Option<&[WordTiming]> {
+        self.word_timings.get(narration).map(Vec::as_slice)
+    }
 
-    /// Allocate the next `Element` handle and record its metadata.
-    fn mint_element_with_meta(
-        &mut self,
-        kind: ElementKind,
-        content: String,
-        items: Vec<String>,
-    ) -> Element {
-        let id = self.next_element_id;
-        self.next_element_id += 1;
+    /// Burn captions into the rendered frames, split into cues by `config`.
+    ///
+    /// Off (`None`) by default. Only the build sets this, from
+    /// [`BuildConfig::burn_in_captions`](crate::build::BuildConfig::burn_in_captions).
+    pub(crate) fn set_burned_in_captions(&mut self, config: Option<CaptionConfig>) {
+        self.burned_in_captions = config;
+    }
 
-        let created_at = self.timeline.total_duration();
-        let segments_at_creation = self.timeline.segments().len();
+    /// The rules for burned-in captions, if they are enabled.
+    pub(crate) fn burned_in_captions(&self) -> Option<&CaptionConfig> {
+        self.burned_in_captions.as_ref()
+    }
 
-        self.elements.push(ElementRecord {
-            id,
-            kind,
-            content,
-            items,
-            created_at,
-            segments_at_creation,
-            ended_at: None,
-            segments_at_end: None,
-        });
+    // -- Internal helpers --------------------------------------------------
 
-        Element(id)
+    /// Resolve an `Element` handle to its record ID, checking that it exists.
+    fn element_id(&self, element: Element) -> Result<u64, ElementError> {
+        if self.elements.iter().any(|rec| rec.id == element.0) {
+            Ok(element.0)
+        } else {
+            Err(ElementError::Unknown(element))
+        }
     }
-}
 
-impl Default for M {
-    fn default() -> Self {
-        Self::new()
+    /// The voice to record on a new narration: the active one once
+    /// [`voice`](Self::voice) has been called, `None` before.
+    fn narration_voice(&self) -> Option<Voice> {
+        self.voice_chosen.then(|| self.current_voice.clone())
     }
-}
+
+    /// Number of segments that precede the current recording position.
+    ///
+    /// Inside a [`together`](Self::together) block this is the block's first
+    /// segment, so everything recorded in the block shares its start time.
+    fn cursor(&self) -> usize {
+        self.parallel_block
+            .unwrap_or_else(|| self.timeline.segments().len())
+    }
+
+    /// Whether the segments from `first` onwards are all part of element
+    /// `id`'s entrance: none is narration, and each one after `first` either
+    /// plays alongside it or animates the element.
+    fn is_latest_entrance(&self, first: usize, id: u64) -> bool {
+        let segments = self.timeline.segments();
+        (first..segments.len()).all(|j| {
+            let animates = || {
+                self.animations
+                    .iter()
+                    .any(|rec| rec.segment_index == j && rec.target_ids.contains(&id))
+            };
+            !matches!(segments[j], Segment::Narration { .. })
+                && (j == first || self.timeline.anchor(j) == Some(first) || animates())
+        })
+    }
+
+    /// Append a segment, overlapping it with the open `together` block if any.
+    fn push_segment(&mut self, segment: Segment) {
+        match self.parallel_block {
+            Some(first) if first < self.timeline.segments().len() => {
+                self.timeline.add_segment_with(segment, first);
+            }
+            _ => self.timeline.add_segment(segment),
+        }
+    }
+
+    /// Move word-anchored animations onto their words and recompute every
+    /// record's timestamps from the timeline.
+    fn retime_records(&mut self) {
+        for cue in &self.word_cues {
+            let offset =
+                word_start(&self.timeline, &self.word_timings, cue.narration_index, cue.word);
+            self.timeline.set_anchor_offset(cue.segment_index, offset);
+        }
+
+        // Recompute element created_at and ended_at timestamps. Start times
+        // are computed once up front; recomputing them per record would make
+        // this quadratic in the number of segments.
+        let starts = self.timeline.segment_starts();
+        let times_after = self.timeline.times_after();
+        let time_after = |count: usize| times_after[count.min(times_after.len() - 1)];
+
+        for rec in &mut self.elements {
+            rec.created_at = time_after(rec.segments_at_creation);
+            if let Some(exit) = rec.exit_segment {
+                rec.ended_at = Some(exit_end(&self.timeline, &starts, exit));
+            } else if let Some(seg_count) = rec.segments_at_end {
+                rec.ended_at = Some(time_after(seg_count));
+            }
+        }
+
+        for rec in &mut self.themes {
+            rec.changed_at = time_after(rec.segments_at_change);
+        }
+
+        for rec in &mut self.music {
+            rec.started_at = time_after(rec.segments_at_start);
+        }
+
+        for rec in &mut self.sfx {
+            rec.cued_at = time_after(rec.segments_at_cue);
+        }
+    }
+
+    /// Record a sound effect starting after the first `seg_count` segments.
+    fn cue_sfx(&mut self, sfx: Sfx, seg_count: usize) {
+        self.sfx.push(SfxRecord {
+            sfx,
+            cued_at: self.timeline.time_after(seg_count),
+            segments_at_cue: seg_count,
+        });
+    }
+
+    /// Record an animation segment on the timeline and bind it to `target_ids`.
+    fn record_animation(
+        &mut self,
+        technique: Box<dyn moron_techniques::Technique>,
+        target_ids: Vec<u64>,
+    ) {
+        let segment_index = self.timeline.segments().len();
+        self.push_segment(Segment::Animation {
+            name: technique.name().to_string(),
+            duration: technique.duration(),
+        });
+        self.animations.push(AnimationRecord {
+            technique,
+            target_ids,
+            segment_index,
+            exit: false,
+            emphasis: false,
+        });
+    }
+
+    /// Record an animation segment that starts on word `word` of the most
+    /// recent narration, falling back to a sequential animation if there is
+    /// no narration yet.
+    ///
+    /// Fails if a segment after the narration doesn't overlap it (word cues
+    /// and the rest of a `together` block do), or if a target was created
+    /// after it.
+    fn record_word_animation(
+        &mut self,
+        technique: Box<dyn moron_techniques::Technique>,
+        target_ids: Vec<u64>,
+        word: usize,
+    ) -> Result<(), ElementError> {
+        let Some(&narration_index) = self.timeline.narration_indices().last() else {
+            self.record_animation(technique, target_ids);
+            return Ok(());
+        };
+
+        let overlaps = |j: usize| {
+            self.timeline
+                .anchor(j)
+                .is_some_and(|anchor| anchor <= narration_index)
+        };
+        if !(narration_index + 1..self.timeline.segments().len()).all(overlaps) {
+            return Err(ElementError::NarrationNotLatest);
+        }
+        if let Some(late) = self.elements.iter().find(|e| {
+            target_ids.contains(&e.id) && e.segments_at_creation > narration_index
+        }) {
+            return Err(ElementError::CreatedAfterNarration(Element(late.id)));
+        }
+
+        let segment_index = self.timeline.segments().len();
+        let offset = word_start(&self.timeline, &self.word_timings, narration_index, word);
+        self.timeline.add_segment_at(
+            Segment::Animation {
+                name: technique.name().to_string(),
+                duration: technique.duration(),
+            },
+            narration_index,
+            offset,
+        );
+        self.animations.push(AnimationRecord {
+            technique,
+            target_ids,
+            segment_index,
+            exit: false,
+            emphasis: false,
+        });
+        self.word_cues.push(WordCueRecord {
+            segment_index,
+            narration_index,
+            word,
+        });
+        Ok(())
+    }
+
+    /// Allocate the next `Element` handle and record its metadata.
+    fn mint_element_with_meta(
+        &mut self,
+        kind: ElementKind,
+        content: String,
+        items: Vec<String>,
+    ) -> Element {
+        let id = self.next_element_id;
+        self.next_element_id += 1;
+
+        let segments_at_creation = self.cursor();
+        let created_at = self.timeline.time_after(segments_at_creation);
+
+        self.elements.push(ElementRecord {
+            id,
+            kind,
+            content,
+            items,
+            panes: Vec::new(),
+            created_at,
+            segments_at_creation,
+            ended_at: None,
+            segments_at_end: None,
+            exit_segment: None,
+        });
+
+        Element(id)
+    }
+}
+
+/// End time of the exit animation or transition segment at `index`, given
+/// the timeline's `starts`.
+fn exit_end(timeline: &Timeline, starts: &[f64], index: usize) -> f64 {
+    starts.get(index).copied().unwrap_or(0.0) + timeline.segments()[index].duration()
+}
+
+/// Seconds into the narration segment at `narration_index` at which word
+/// `word` is spoken, from resolved word timings if available or estimated
+/// from word lengths otherwise. Past the last word, the narration's end.
+fn word_start(
+    timeline: &Timeline,
+    word_timings: &[Vec<WordTiming>],
+    narration_index: usize,
+    word: usize,
+) -> f64 {
+    let Some(Segment::Narration { text, duration, .. }) = timeline.segments().get(narration_index)
+    else {
+        return 0.0;
+    };
+    let ordinal = timeline
+        .narration_indices()
+        .iter()
+        .position(|&i| i == narration_index);
+    let words = match ordinal.and_then(|n| word_timings.get(n)) {
+        Some(words) => words.clone(),
+        None => alignment::estimate(text, *duration),
+    };
+    words.get(word).map_or(*duration, |w| w.start)
+}
+
+/// Estimate how long `text` takes to speak at [`DEFAULT_NARRATION_WPM`].
+fn estimate_narration_duration(text: &str) -> f64 {
+    let words = text.split_whitespace().count().max(1) as f64;
+    words * 60.0 / DEFAULT_NARRATION_WPM
+}
+
+impl Default for M {
+    fn default() -> Self {
+        Self::new()
+    }
+}
 
 // ---------------------------------------------------------------------------
 // Tests
@@ -485,6 +1371,26 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn narration_records_active_voice() {
+        let mut m = M::new();
+        // No voice chosen yet: left to the build's default backend.
+        m.narrate("First narrator");
+        m.voice(Voice::piper());
+        m.narrate("Second narrator");
+
+        let voices: Vec<_> = m
+            .timeline()
+            .segments()
+            .iter()
+            .map(|seg| match seg {
+                Segment::Narration { voice, .. } => voice.clone(),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(voices, vec![None, Some(Voice::piper())]);
+    }
+
     #[test]
     fn play_records_animation_segments() {
         use moron_techniques::{FadeIn, FadeUp, Technique};
@@ -498,6 +1404,448 @@ mod tests {
         assert!((total - expected).abs() < f64::EPSILON);
     }
 
+    #[test]
+    fn play_on_targets_given_element() {
+        use moron_techniques::FadeIn;
+        let mut m = M::new();
+        let title = m.title("First");
+        m.show("Second");
+        m.play_on(title, FadeIn::default()).unwrap();
+
+        assert_eq!(m.timeline().segments().len(), 1);
+        assert_eq!(m.animations()[0].target_ids, vec![title.0]);
+    }
+
+    #[test]
+    fn play_on_all_targets_every_element() {
+        use moron_techniques::FadeIn;
+        let mut m = M::new();
+        let a = m.title("A");
+        let b = m.show("B");
+        m.play_on_all(&[a, b], FadeIn::default()).unwrap();
+
+        assert_eq!(m.timeline().segments().len(), 1);
+        assert_eq!(m.animations()[0].target_ids, vec![a.0, b.0]);
+    }
+
+    #[test]
+    fn play_on_all_empty_records_segment_without_targets() {
+        use moron_techniques::FadeIn;
+        let mut m = M::new();
+        m.title("A");
+        m.play_on_all(&[], FadeIn { duration: 0.7 }).unwrap();
+
+        assert_eq!(m.timeline().segments().len(), 1);
+        assert!((m.timeline().total_duration() - 0.7).abs() < f64::EPSILON);
+        assert!(m.animations()[0].target_ids.is_empty());
+    }
+
+    #[test]
+    fn play_on_unknown_element_is_rejected() {
+        use moron_techniques::FadeIn;
+        let mut other = M::new();
+        other.title("elsewhere");
+        other.title("elsewhere too");
+        let foreign = other.show("not in m");
+
+        let mut m = M::new();
+        let known = m.title("A");
+
+        let err = m.play_on(foreign, FadeIn::default());
+        assert_eq!(err, Err(ElementError::Unknown(foreign)));
+
+        // A single unknown handle rejects the whole call.
+        let err = m.play_on_all(&[known, foreign], FadeIn::default());
+        assert_eq!(err, Err(ElementError::Unknown(foreign)));
+
+        // Nothing was recorded.
+        assert!(m.timeline().segments().is_empty());
+        assert!(m.animations().is_empty());
+    }
+
+    #[test]
+    fn element_error_display() {
+        let msg = format!("{}", ElementError::Unknown(Element(42)));
+        assert!(msg.contains("unknown element"));
+        assert!(msg.contains("42"));
+    }
+
+    #[test]
+    fn together_overlaps_segments() {
+        use moron_techniques::FadeIn;
+        let mut m = M::new();
+        m.wait(1.0);
+        m.title("A");
+        m.together(|m| {
+            m.play(FadeIn { duration: 0.5 });
+            m.narrate("one two three four five"); // 2.0s at 150 WPM
+            m.beat();
+        });
+        m.wait(1.0);
+
+        let tl = m.timeline();
+        assert_eq!(tl.segments().len(), 5);
+        assert_eq!(tl.segment_starts(), vec![0.0, 1.0, 1.0, 1.0, 3.0]);
+        assert!((tl.total_duration() - 4.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn together_elements_appear_at_block_start() {
+        use moron_techniques::FadeIn;
+        let mut m = M::new();
+        m.wait(1.0);
+        m.together(|m| {
+            m.narrate("one two three four five");
+            m.title("Inside");
+            m.play(FadeIn::default());
+            m.together(|m| {
+                m.show("Nested");
+            });
+        });
+        let after = m.show("After");
+
+        let elems = m.elements();
+        assert!((elems[0].created_at - 1.0).abs() < f64::EPSILON);
+        assert!((elems[1].created_at - 1.0).abs() < f64::EPSILON);
+        assert!((elems[after.0 as usize].created_at - 3.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn resolve_durations_inside_together() {
+        let mut m = M::new();
+        m.together(|m| {
+            m.narrate("short");
+            m.wait(1.0);
+        });
+        m.title("Next");
+
+        // Stretching the narration past the pause pushes the title back.
+        m.resolve_narration_durations(&[2.5]).unwrap();
+        assert!((m.elements()[0].created_at - 2.5).abs() < f64::EPSILON);
+        assert!((m.timeline().total_duration() - 2.5).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn narrate_over_overlaps_entrance_animation() {
+        use moron_techniques::FadeIn;
+        let mut m = M::new();
+        let title = m.title("Hello");
+        m.play(FadeIn { duration: 1.0 });
+        m.narrate_over(title, "one two three four five").unwrap(); // 2.0s
+        let next = m.show("Next");
+
+        let tl = m.timeline();
+        assert_eq!(tl.anchor(1), Some(0));
+        assert_eq!(tl.segment_starts(), vec![0.0, 0.0]);
+        // Overlap, not sum: max(1.0, 2.0) instead of 3.0.
+        assert!((tl.total_duration() - 2.0).abs() < f64::EPSILON);
+        assert!((m.elements()[next.0 as usize].created_at - 2.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn narrate_over_without_animation_starts_at_element() {
+        let mut m = M::new();
+        m.wait(1.0);
+        let title = m.title("Hello");
+        m.wait(2.0);
+        m.narrate_over(title, "one two").unwrap();
+
+        // Anchored to the pause that followed the title's creation.
+        assert_eq!(m.timeline().anchor(2), Some(1));
+        assert!((m.timeline().total_duration() - 3.0).abs() < f64::EPSILON);
+
+        // Nothing recorded since creation: behaves like `narrate`.
+        let mut m = M::new();
+        let title = m.title("Hello");
+        m.narrate_over(title, "one two").unwrap();
+        assert_eq!(m.timeline().anchor(0), None);
+        assert_eq!(m.narration_count(), 1);
+    }
+
+    #[test]
+    fn narrate_over_after_later_segments_is_rejected() {
+        use moron_techniques::FadeIn;
+        let mut m = M::new();
+        let title = m.title("Hello");
+        m.play(FadeIn { duration: 1.0 });
+        m.narrate("Meanwhile, something else.");
+
+        assert_eq!(
+            m.narrate_over(title, "too late"),
+            Err(ElementError::EntranceNotLatest(title))
+        );
+        assert_eq!(m.timeline().segments().len(), 2);
+
+        // A second narration over the same entrance would talk over the first.
+        let mut m = M::new();
+        let title = m.title("Hello");
+        m.play(FadeIn { duration: 1.0 });
+        m.narrate_over(title, "first").unwrap();
+        assert_eq!(
+            m.narrate_over(title, "second"),
+            Err(ElementError::EntranceNotLatest(title))
+        );
+
+        // Further animation of the element still counts as its entrance.
+        let mut m = M::new();
+        let title = m.title("Hello");
+        m.play(FadeIn { duration: 1.0 });
+        m.play(FadeIn { duration: 0.5 });
+        m.narrate_over(title, "one two").unwrap();
+        assert_eq!(m.timeline().anchor(2), Some(0));
+    }
+
+    #[test]
+    fn narrate_over_inside_together_uses_block_start() {
+        use moron_techniques::FadeIn;
+        let mut m = M::new();
+        let title = m.title("Hello");
+        m.play(FadeIn { duration: 1.0 });
+        m.wait(1.0);
+        m.together(|m| {
+            m.wait(0.5);
+            m.narrate_over(title, "one two").unwrap();
+        });
+
+        assert_eq!(m.timeline().anchor(3), Some(2));
+        assert_eq!(m.timeline().segment_starts()[3], 2.0);
+    }
+
+    #[test]
+    fn narrate_over_unknown_element_is_rejected() {
+        let mut other = M::new();
+        other.title("A");
+        let foreign = other.title("B");
+
+        let mut m = M::new();
+        assert_eq!(
+            m.narrate_over(foreign, "text"),
+            Err(ElementError::Unknown(foreign))
+        );
+        assert!(m.timeline().segments().is_empty());
+    }
+
+    #[test]
+    fn resolve_durations_retimes_after_narrate_over() {
+        use moron_techniques::FadeIn;
+        let mut m = M::new();
+        let title = m.title("Hello");
+        m.play(FadeIn { duration: 1.0 });
+        m.narrate_over(title, "estimated").unwrap();
+        m.show("Next");
+
+        // Longer than the animation: the next element waits for the voice.
+        m.resolve_narration_durations(&[3.0]).unwrap();
+        assert!((m.elements()[1].created_at - 3.0).abs() < f64::EPSILON);
+
+        // Shorter than the animation: the animation sets the pace again.
+        m.resolve_narration_durations(&[0.5]).unwrap();
+        assert!((m.elements()[1].created_at - 1.0).abs() < f64::EPSILON);
+        assert!((m.timeline().total_duration() - 1.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn hide_ends_single_element() {
+        let mut m = M::new();
+        let a = m.title("A");
+        m.show("B");
+        m.wait(1.0);
+        m.hide(a).unwrap();
+        m.wait(1.0);
+
+        let elems = m.elements();
+        assert_eq!(elems[0].ended_at, Some(1.0));
+        assert_eq!(elems[0].segments_at_end, Some(1));
+        assert!(elems[0].exit_segment.is_none());
+        assert!(elems[1].ended_at.is_none());
+
+        // Hiding again keeps the original end time.
+        m.hide(a).unwrap();
+        assert_eq!(m.elements()[0].ended_at, Some(1.0));
+    }
+
+    #[test]
+    fn hide_with_records_exit_window() {
+        use moron_techniques::FadeOut;
+        let mut m = M::new();
+        let a = m.title("A");
+        m.wait(1.0);
+        m.hide_with(a, FadeOut { duration: 0.5 }).unwrap();
+
+        assert_eq!(m.timeline().segments().len(), 2);
+        let anim = &m.animations()[0];
+        assert!(anim.exit);
+        assert_eq!(anim.target_ids, vec![a.0]);
+        assert_eq!(anim.segment_index, 1);
+
+        let rec = &m.elements()[0];
+        assert_eq!(rec.exit_segment, Some(1));
+        assert!((rec.ended_at.unwrap() - 1.5).abs() < f64::EPSILON);
+
+        // clear() leaves the exit window alone.
+        m.clear();
+        assert!((m.elements()[0].ended_at.unwrap() - 1.5).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn hide_with_already_hidden_element_is_noop() {
+        use moron_techniques::FadeOut;
+        let mut m = M::new();
+        let a = m.title("A");
+        m.clear();
+        m.hide_with(a, FadeOut::default()).unwrap();
+
+        assert!(m.timeline().segments().is_empty());
+        assert!(m.animations().is_empty());
+    }
+
+    #[test]
+    fn hide_unknown_element_is_rejected() {
+        use moron_techniques::FadeOut;
+        let mut other = M::new();
+        other.title("A");
+        let foreign = other.title("B");
+
+        let mut m = M::new();
+        assert_eq!(m.hide(foreign), Err(ElementError::Unknown(foreign)));
+        assert_eq!(
+            m.hide_with(foreign, FadeOut::default()),
+            Err(ElementError::Unknown(foreign))
+        );
+        assert!(m.timeline().segments().is_empty());
+    }
+
+    #[test]
+    fn resolve_durations_retimes_exit_window() {
+        use moron_techniques::FadeOut;
+        let mut m = M::new();
+        let a = m.title("A");
+        m.narrate("estimated");
+        m.together(|m| {
+            m.hide_with(a, FadeOut { duration: 0.5 }).unwrap();
+            m.wait(2.0);
+        });
+
+        m.resolve_narration_durations(&[1.0]).unwrap();
+        // Exit ends with its own segment, not with the longer pause beside it.
+        assert!((m.elements()[0].ended_at.unwrap() - 1.5).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn resolve_durations_retimes_theme_changes() {
+        let mut m = M::new();
+        m.narrate("One two three four five");
+        m.theme(Theme::light());
+
+        m.resolve_narration_durations(&[3.0]).unwrap();
+        assert!((m.themes()[0].changed_at - 3.0).abs() < f64::EPSILON);
+        assert_eq!(m.current_theme().name, "moron-light");
+    }
+
+    #[test]
+    fn clear_with_records_transition() {
+        use moron_techniques::Crossfade;
+        let mut m = M::new();
+        m.title("A");
+        m.show("B");
+        m.wait(1.0);
+        m.clear_with(Crossfade { duration: 0.5 });
+        m.title("C");
+
+        assert_eq!(m.timeline().segments().len(), 2);
+        assert!((m.timeline().total_duration() - 1.5).abs() < f64::EPSILON);
+
+        let tr = &m.transitions()[0];
+        assert_eq!(tr.segment_index, 1);
+        assert_eq!(tr.outgoing_ids, vec![0, 1]);
+
+        // Outgoing elements end with the transition; the incoming one starts after it.
+        let elems = m.elements();
+        assert_eq!(elems[0].ended_at, Some(1.5));
+        assert_eq!(elems[1].exit_segment, Some(1));
+        assert_eq!(elems[2].segments_at_creation, 2);
+        assert!(elems[2].ended_at.is_none());
+    }
+
+    #[test]
+    fn diagram_creates_component_element() {
+        let mut m = M::new();
+        let props = serde_json::json!({ "layers": 3 });
+        let el = m.diagram("stack", props.clone());
+
+        let rec = &m.elements()[el.0 as usize];
+        assert_eq!(
+            rec.kind,
+            ElementKind::Component {
+                name: "stack".to_string(),
+                props
+            }
+        );
+        assert_eq!(rec.content, "stack");
+        assert!(rec.items.is_empty());
+    }
+
+    #[test]
+    fn compare_records_two_panes() {
+        let mut m = M::new();
+        let el = m.compare(
+            Pane::metric("Before", "900ms", Direction::Up),
+            Pane::steps("After", &["cache", "batch"]),
+        );
+
+        let rec = &m.elements()[el.0 as usize];
+        assert_eq!(rec.kind, ElementKind::Compare);
+        assert_eq!(rec.content, "Before vs After");
+        assert!(rec.items.is_empty());
+        assert_eq!(rec.panes.len(), 2);
+        assert_eq!(rec.panes[0].label, "Before");
+        assert_eq!(
+            rec.panes[0].content,
+            crate::frame::PaneContent::Metric {
+                value: "900ms".to_string(),
+                direction: "up".to_string()
+            }
+        );
+        assert_eq!(rec.panes[1], Pane::steps("After", &["cache", "batch"]));
+    }
+
+    #[test]
+    fn focus_creates_linked_annotation() {
+        let mut m = M::new();
+        let title = m.title("Latency");
+        m.show("Other");
+        let note = m.focus(title, "This is the bottleneck").unwrap();
+
+        let rec = &m.elements()[note.0 as usize];
+        assert_eq!(rec.kind, ElementKind::Annotation { target: title.0 });
+        assert_eq!(rec.content, "This is the bottleneck");
+
+        // The highlight plays on the target, not on the annotation.
+        assert_eq!(m.timeline().segments().len(), 1);
+        assert_eq!(m.animations()[0].technique.name(), "Highlight");
+        assert_eq!(m.animations()[0].target_ids, vec![title.0]);
+
+        let focus = &m.focuses()[0];
+        assert_eq!(focus.target_id, title.0);
+        assert_eq!(focus.annotation_id, note.0);
+        assert_eq!(focus.segment_index, 0);
+    }
+
+    #[test]
+    fn focus_unknown_element_is_rejected() {
+        let mut other = M::new();
+        other.title("A");
+        let foreign = other.title("B");
+
+        let mut m = M::new();
+        assert_eq!(
+            m.focus(foreign, "note"),
+            Err(ElementError::Unknown(foreign))
+        );
+        assert!(m.elements().is_empty());
+        assert!(m.timeline().segments().is_empty());
+    }
+
     #[test]
     fn beat_adds_silence() {
         let mut m = M::new();
@@ -627,6 +1975,141 @@ mod tests {
         );
     }
 
+    #[test]
+    fn resolve_word_timings_per_narration() {
+        let mut m = M::new();
+        m.narrate("Hello");
+        m.wait(1.0);
+        m.narrate("World");
+        assert!(m.word_timings(0).is_none());
+
+        let word = |w: &str| WordTiming {
+            word: w.to_string(),
+            start: 0.0,
+            end: 0.4,
+        };
+        let err = m.resolve_word_timings(vec![vec![word("Hello")]]).unwrap_err();
+        assert_eq!(
+            err,
+            ResolveDurationError::LengthMismatch { expected: 2, provided: 1 }
+        );
+
+        m.resolve_word_timings(vec![vec![word("Hello")], vec![word("World")]])
+            .unwrap();
+        assert_eq!(m.word_timings(1).unwrap()[0].word, "World");
+        assert!(m.word_timings(2).is_none());
+    }
+
+    #[test]
+    fn at_word_starts_on_estimated_word() {
+        use moron_techniques::FadeIn;
+
+        let mut m = M::new();
+        m.wait(1.0);
+        let bullet = m.show("Speed");
+        m.narrate("The key is speed"); // 1.6s; weights 4 + 4 + 3 + 6 = 17
+        m.at_word(3, FadeIn::default()).unwrap();
+
+        let tl = m.timeline();
+        assert_eq!(tl.anchor(2), Some(1));
+        let expected = 1.0 + 11.0 / 17.0 * 1.6;
+        assert!((tl.segment_start(2).unwrap() - expected).abs() < 1e-9);
+        assert_eq!(m.animations()[0].target_ids, vec![bullet.0]);
+    }
+
+    #[test]
+    fn at_word_follows_resolved_timings() {
+        use moron_techniques::{FadeIn, Technique};
+
+        let mut m = M::new();
+        m.show("Speed");
+        m.narrate("The key is speed");
+        m.at_word(3, FadeIn::default()).unwrap();
+        m.title("Next");
+
+        m.resolve_narration_durations(&[3.4]).unwrap();
+        let estimated = 11.0 / 17.0 * 3.4;
+        assert!((m.timeline().segment_start(1).unwrap() - estimated).abs() < 1e-9);
+
+        let timing = |word: &str, start: f64| WordTiming {
+            word: word.to_string(),
+            start,
+            end: start + 0.3,
+        };
+        m.resolve_word_timings(vec![vec![
+            timing("The", 0.1),
+            timing("key", 0.5),
+            timing("is", 0.9),
+            timing("speed", 3.2),
+        ]])
+        .unwrap();
+        assert!((m.timeline().segment_start(1).unwrap() - 3.2).abs() < 1e-9);
+
+        // The animation now runs past the narration, so what follows waits.
+        let end = 3.2 + FadeIn::default().duration();
+        assert!((m.elements()[1].created_at - end).abs() < 1e-9);
+    }
+
+    #[test]
+    fn at_word_past_end_or_without_narration() {
+        use moron_techniques::FadeIn;
+
+        let mut m = M::new();
+        m.show("A");
+        m.at_word(2, FadeIn::default()).unwrap();
+        assert_eq!(m.timeline().anchor(0), None);
+
+        m.narrate("one two");
+        m.at_word(9, FadeIn::default()).unwrap();
+        let narration_end = m.timeline().segment_start(1).unwrap() + 0.8;
+        assert!((m.timeline().segment_start(2).unwrap() - narration_end).abs() < 1e-9);
+
+        let err = m.at_word_on(Element(99), 0, FadeIn::default()).unwrap_err();
+        assert_eq!(err, ElementError::Unknown(Element(99)));
+        assert_eq!(m.timeline().segments().len(), 3);
+    }
+
+    #[test]
+    fn at_word_rejects_targets_created_after_narration() {
+        use moron_techniques::FadeIn;
+
+        let mut m = M::new();
+        let early = m.show("Early");
+        m.narrate("one two three");
+        let late = m.show("Late");
+
+        let err = m.at_word(1, FadeIn::default()).unwrap_err();
+        assert_eq!(err, ElementError::CreatedAfterNarration(late));
+        assert!(m.at_word_on(late, 1, FadeIn::default()).is_err());
+        assert_eq!(m.timeline().segments().len(), 1);
+
+        // Several cues on the same narration are fine.
+        m.at_word_on(early, 0, FadeIn::default()).unwrap();
+        m.at_word_on(early, 2, FadeIn::default()).unwrap();
+        assert_eq!(m.timeline().segments().len(), 3);
+    }
+
+    #[test]
+    fn at_word_rejects_segments_after_narration() {
+        use moron_techniques::FadeIn;
+
+        let mut m = M::new();
+        let bullet = m.show("Bullet");
+        m.narrate("one two three");
+        m.wait(1.0);
+
+        let err = m.at_word_on(bullet, 1, FadeIn::default()).unwrap_err();
+        assert_eq!(err, ElementError::NarrationNotLatest);
+        assert_eq!(m.timeline().segments().len(), 2);
+
+        // Segments played together with the narration don't count.
+        m.together(|m| {
+            m.narrate("four five");
+            m.play(FadeIn::default());
+        });
+        m.at_word_on(bullet, 1, FadeIn::default()).unwrap();
+    }
+
     #[test]
     fn resolve_preserves_non_narration_timing() {
         let mut m = M::new();
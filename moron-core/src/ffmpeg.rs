@@ -19,9 +19,9 @@
 use std::path::{Path, PathBuf};
 use std::process::Command;
 
-use moron_voice::AudioClip;
+use moron_voice::{AudioClip, MixClip, Mixer};
 
-use crate::timeline::Timeline;
+use crate::timeline::{Segment, Timeline};
 
 // ---------------------------------------------------------------------------
 // FfmpegError
@@ -52,6 +52,15 @@ pub enum FfmpegError {
         /// Captured stderr output from FFmpeg.
         stderr: String,
     },
+
+    /// FFprobe could not read a media file.
+    #[error("could not probe {path}: {message}")]
+    ProbeFailed {
+        /// The file that was probed.
+        path: PathBuf,
+        /// Description of the failure.
+        message: String,
+    },
 }
 
 // ---------------------------------------------------------------------------
@@ -203,6 +212,25 @@ pub fn encode(config: &EncodeConfig) -> Result<(), FfmpegError> {
 // mux_audio
 // ---------------------------------------------------------------------------
 
+/// A subtitle file to embed in the output as a soft (toggleable) track.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SubtitleTrack {
+    /// Path to the subtitle file (`.srt` or `.vtt`).
+    pub path: PathBuf,
+    /// ISO 639-2 language code for the track, e.g. `"eng"`.
+    pub language: String,
+}
+
+impl SubtitleTrack {
+    /// Create a subtitle track from a file and its language code.
+    pub fn new(path: impl Into<PathBuf>, language: impl Into<String>) -> Self {
+        Self {
+            path: path.into(),
+            language: language.into(),
+        }
+    }
+}
+
 /// Mux a video file with an audio file into a final `.mp4`.
 ///
 /// The video stream is copied without re-encoding (`-c:v copy`).
@@ -219,6 +247,27 @@ pub fn mux_audio(
     video_path: &Path,
     audio_path: &Path,
     output_path: &Path,
+) -> Result<(), FfmpegError> {
+    mux_streams(video_path, audio_path, None, output_path)
+}
+
+/// Mux video, audio and an optional subtitle track into a final `.mp4`.
+///
+/// Works like [`mux_audio`]; when `subtitles` is given, the file is also
+/// embedded as a `mov_text` stream tagged with its language, which players
+/// show as a caption track that can be switched on and off.
+///
+/// # Errors
+///
+/// Returns [`FfmpegError`] if:
+/// - The video, audio or subtitle file does not exist
+/// - FFmpeg is not installed or not on PATH
+/// - FFmpeg exits with a non-zero status
+pub fn mux_streams(
+    video_path: &Path,
+    audio_path: &Path,
+    subtitles: Option<&SubtitleTrack>,
+    output_path: &Path,
 ) -> Result<(), FfmpegError> {
     // Validate inputs exist.
     if !video_path.exists() {
@@ -231,10 +280,15 @@ pub fn mux_audio(
             reason: format!("audio file does not exist: {}", audio_path.display()),
         });
     }
+    if let Some(track) = subtitles.filter(|t| !t.path.exists()) {
+        return Err(FfmpegError::InvalidInput {
+            reason: format!("subtitle file does not exist: {}", track.path.display()),
+        });
+    }
 
     detect_ffmpeg()?;
 
-    let args = build_mux_args(video_path, audio_path, output_path);
+    let args = build_mux_args(video_path, audio_path, subtitles, output_path);
 
     let output = Command::new("ffmpeg")
         .args(&args)
@@ -261,6 +315,114 @@ pub fn mux_audio(
     Ok(())
 }
 
+// ---------------------------------------------------------------------------
+// Video clips
+// ---------------------------------------------------------------------------
+
+/// Read the duration of a media file in seconds using `ffprobe`.
+///
+/// # Errors
+///
+/// Returns [`FfmpegError::InvalidInput`] if the file does not exist,
+/// [`FfmpegError::NotFound`] if `ffprobe` cannot be run, and
+/// [`FfmpegError::ProbeFailed`] if it reports no usable duration.
+pub fn probe_duration(path: &Path) -> Result<f64, FfmpegError> {
+    if !path.exists() {
+        return Err(FfmpegError::InvalidInput {
+            reason: format!("clip does not exist: {}", path.display()),
+        });
+    }
+
+    let output = run_ffprobe(path, &[
+        "-show_entries",
+        "format=duration",
+        "-of",
+        "default=noprint_wrappers=1:nokey=1",
+    ])?;
+
+    parse_probe_duration(&output).ok_or_else(|| FfmpegError::ProbeFailed {
+        path: path.to_path_buf(),
+        message: format!("no duration in ffprobe output {:?}", output.trim()),
+    })
+}
+
+/// Check whether a media file has at least one audio stream.
+///
+/// # Errors
+///
+/// Returns [`FfmpegError`] if `ffprobe` cannot be run or fails.
+pub fn has_audio_stream(path: &Path) -> Result<bool, FfmpegError> {
+    let output = run_ffprobe(path, &[
+        "-select_streams",
+        "a",
+        "-show_entries",
+        "stream=index",
+        "-of",
+        "csv=p=0",
+    ])?;
+    Ok(!output.trim().is_empty())
+}
+
+/// Decode the audio of a clip into a mono [`AudioClip`] at `sample_rate`.
+///
+/// The result is exactly `duration` seconds long: shorter audio is padded
+/// with silence and longer audio is cut. Clips without an audio stream
+/// produce silence.
+///
+/// # Errors
+///
+/// Returns [`FfmpegError`] if FFmpeg cannot be run or fails to decode.
+pub fn extract_clip_audio(
+    path: &Path,
+    duration: f64,
+    sample_rate: u32,
+) -> Result<AudioClip, FfmpegError> {
+    let mut clip = AudioClip::silence(duration, sample_rate);
+    if !has_audio_stream(path)? {
+        return Ok(clip);
+    }
+
+    let args = build_clip_audio_args(path, duration, sample_rate);
+    let raw = run_ffmpeg(&args, "audio extraction")?;
+
+    for (dst, chunk) in clip.data.iter_mut().zip(raw.chunks_exact(4)) {
+        *dst = f32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]);
+    }
+    Ok(clip)
+}
+
+/// Write the frames of a clip into a frame directory, starting at
+/// `first_frame`.
+///
+/// Frames are resampled to `fps` and letterboxed to `width`x`height`, and
+/// use the same `frame_%06d.png` naming as the renderer, so they replace
+/// rendered frames in place. At most `frame_count` frames are written.
+///
+/// # Errors
+///
+/// Returns [`FfmpegError`] if FFmpeg cannot be run or fails to decode.
+pub fn extract_clip_frames(
+    path: &Path,
+    output_dir: &Path,
+    first_frame: u32,
+    frame_count: u32,
+    fps: u32,
+    width: u32,
+    height: u32,
+) -> Result<(), FfmpegError> {
+    let args = build_clip_frame_args(
+        path,
+        output_dir,
+        first_frame,
+        frame_count,
+        fps,
+        width,
+        height,
+    );
+    run_ffmpeg(&args, "frame extraction")?;
+    Ok(())
+}
+
 // ---------------------------------------------------------------------------
 // assemble_audio_track
 // ---------------------------------------------------------------------------
@@ -270,10 +432,14 @@ pub fn mux_audio(
 /// For each segment:
 /// - **Narration** segments use the corresponding clip from `narration_clips`
 ///   (if provided), falling back to silence when `None`.
+/// - **Clip** segments use the corresponding clip from `clip_audio` (if
+///   provided), falling back to silence when `None`.
 /// - All other segment types produce silence of their specified duration.
 ///
-/// The concatenation and WAV encoding path is the same regardless of whether
-/// real TTS audio is supplied.
+/// Each audio clip is placed at its segment's start time, so audio that
+/// overlaps other segments lands at the right offset and overlapping audio
+/// is summed. The track spans the full timeline duration and is extended if
+/// a clip runs past the end.
 ///
 /// # Arguments
 ///
@@ -282,37 +448,43 @@ pub fn mux_audio(
 /// * `narration_clips` -- optional pre-synthesized audio clips, one per
 ///   narration segment in timeline order. When `None`, all narration segments
 ///   are rendered as silence (backward-compatible behavior).
+/// * `clip_audio` -- optional decoded audio, one per video clip segment in
+///   timeline order (see [`extract_clip_audio`]).
+///
+/// The output is mono at `sample_rate`. Clips in any other format are
+/// downmixed and resampled to match, so TTS voices with different native
+/// rates can share one track.
 pub fn assemble_audio_track(
     timeline: &Timeline,
     sample_rate: u32,
     narration_clips: Option<&[AudioClip]>,
-) -> Result<AudioClip, moron_voice::AudioError> {
-    let mut narration_idx: usize = 0;
-
-    let clips: Vec<AudioClip> = timeline
-        .segments()
-        .iter()
-        .map(|seg| {
-            match seg {
-                crate::timeline::Segment::Narration { .. } => {
-                    if let Some(clips) = narration_clips
-                        && narration_idx < clips.len()
-                    {
-                        let clip = clips[narration_idx].clone();
-                        narration_idx += 1;
-                        return clip;
-                    }
-                    narration_idx += 1;
-                    AudioClip::silence(seg.duration(), sample_rate)
-                }
-                _ => AudioClip::silence(seg.duration(), sample_rate),
-            }
-        })
-        .collect();
+    clip_audio: Option<&[AudioClip]>,
+) -> AudioClip {
+    let starts = timeline.segment_starts();
+    let mut mixer = Mixer::mono(sample_rate).with_duration(timeline.total_duration());
+
+    let starts_of = |want_clip: bool| {
+        timeline
+            .segments()
+            .iter()
+            .zip(&starts)
+            .filter(move |(seg, _)| match seg {
+                Segment::Narration { .. } => !want_clip,
+                Segment::Clip { .. } => want_clip,
+                _ => false,
+            })
+            .map(|(_, &start)| start)
+    };
 
-    AudioClip::concat(&clips, sample_rate, 1)
-}
+    let narrations = starts_of(false).zip(narration_clips.unwrap_or_default());
+    let clips = starts_of(true).zip(clip_audio.unwrap_or_default());
+
+    for (start, clip) in narrations.chain(clips) {
+        mixer.add(MixClip::new(clip.clone(), start));
+    }
 
+    mixer.mix()
+}
 
 // ---------------------------------------------------------------------------
 // Internal helpers
@@ -435,26 +607,190 @@ fn build_ffmpeg_args(config: &EncodeConfig) -> Vec<String> {
     ]
 }
 
+/// Parse the output of `ffprobe -show_entries format=duration`.
+fn parse_probe_duration(output: &str) -> Option<f64> {
+    output
+        .trim()
+        .parse::<f64>()
+        .ok()
+        .filter(|d| d.is_finite() && *d > 0.0)
+}
+
+/// Run `ffprobe` on `path` with the given arguments and return its stdout.
+fn run_ffprobe(path: &Path, args: &[&str]) -> Result<String, FfmpegError> {
+    let output = Command::new("ffprobe")
+        .args(["-v", "error"])
+        .args(args)
+        .arg(path)
+        .output()
+        .map_err(|_| FfmpegError::NotFound)?;
+
+    if !output.status.success() {
+        return Err(FfmpegError::ProbeFailed {
+            path: path.to_path_buf(),
+            message: String::from_utf8_lossy(&output.stderr).trim().to_string(),
+        });
+    }
+
+    Ok(String::from_utf8_lossy(&output.stdout).to_string())
+}
+
+/// Run FFmpeg with `args` and return its stdout.
+fn run_ffmpeg(args: &[String], what: &str) -> Result<Vec<u8>, FfmpegError> {
+    detect_ffmpeg()?;
+
+    let output = Command::new("ffmpeg")
+        .args(args)
+        .output()
+        .map_err(|e| FfmpegError::EncodeFailed {
+            message: format!("failed to spawn FFmpeg process: {e}"),
+            stderr: String::new(),
+        })?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr).to_string();
+        let code = output
+            .status
+            .code()
+            .map(|c| format!("exit code {c}"))
+            .unwrap_or_else(|| "killed by signal".to_string());
+
+        return Err(FfmpegError::EncodeFailed {
+            message: format!("FFmpeg {what} failed with {code}"),
+            stderr,
+        });
+    }
+
+    Ok(output.stdout)
+}
+
+/// Build the FFmpeg command-line arguments for decoding clip audio.
+///
+/// Produces arguments equivalent to:
+/// ```text
+/// ffmpeg -v error -i {clip} -t {duration} -vn -ac 1 -ar {rate} -f f32le pipe:1
+/// ```
+fn build_clip_audio_args(path: &Path, duration: f64, sample_rate: u32) -> Vec<String> {
+    vec![
+        "-v".to_string(),
+        "error".to_string(),
+        "-i".to_string(),
+        path.to_string_lossy().to_string(),
+        "-t".to_string(),
+        duration.to_string(),
+        // Drop video; downmix to mono raw floats on stdout.
+        "-vn".to_string(),
+        "-ac".to_string(),
+        "1".to_string(),
+        "-ar".to_string(),
+        sample_rate.to_string(),
+        "-f".to_string(),
+        "f32le".to_string(),
+        "pipe:1".to_string(),
+    ]
+}
+
+/// Build the FFmpeg command-line arguments for extracting clip frames.
+///
+/// Produces arguments equivalent to:
+/// ```text
+/// ffmpeg -y -i {clip} -vf fps={fps},scale=...,pad=... \
+///   -frames:v {count} -start_number {first} {output_dir}/frame_%06d.png
+/// ```
+fn build_clip_frame_args(
+    path: &Path,
+    output_dir: &Path,
+    first_frame: u32,
+    frame_count: u32,
+    fps: u32,
+    width: u32,
+    height: u32,
+) -> Vec<String> {
+    let output_pattern = output_dir
+        .join("frame_%06d.png")
+        .to_string_lossy()
+        .to_string();
+
+    vec![
+        "-y".to_string(),
+        "-i".to_string(),
+        path.to_string_lossy().to_string(),
+        // Match the scene frame rate, then fit inside the frame with black bars.
+        "-vf".to_string(),
+        format!(
+            "fps={fps},scale={width}:{height}:force_original_aspect_ratio=decrease,\
+             pad={width}:{height}:(ow-iw)/2:(oh-ih)/2:black"
+        ),
+        "-frames:v".to_string(),
+        frame_count.to_string(),
+        "-start_number".to_string(),
+        first_frame.to_string(),
+        output_pattern,
+    ]
+}
+
 /// Build the FFmpeg command-line arguments for muxing video + audio.
 ///
 /// Produces arguments equivalent to:
 /// ```text
 /// ffmpeg -y -i {video} -i {audio} -c:v copy -c:a aac -shortest {output}
 /// ```
-fn build_mux_args(video_path: &Path, audio_path: &Path, output_path: &Path) -> Vec<String> {
-    vec![
+///
+/// With a subtitle track, the streams are mapped explicitly and the track is
+/// added as `mov_text`:
+/// ```text
+/// ffmpeg -y -i {video} -i {audio} -i {subtitles} -map 0:v -map 1:a -map 2:s
+///        -c:v copy -c:a aac -c:s mov_text -metadata:s:s:0 language={lang}
+///        -shortest {output}
+/// ```
+fn build_mux_args(
+    video_path: &Path,
+    audio_path: &Path,
+    subtitles: Option<&SubtitleTrack>,
+    output_path: &Path,
+) -> Vec<String> {
+    let mut args = vec![
         "-y".to_string(),
         "-i".to_string(),
         video_path.to_string_lossy().to_string(),
         "-i".to_string(),
         audio_path.to_string_lossy().to_string(),
+    ];
+
+    if let Some(track) = subtitles {
+        args.extend([
+            "-i".to_string(),
+            track.path.to_string_lossy().to_string(),
+            "-map".to_string(),
+            "0:v".to_string(),
+            "-map".to_string(),
+            "1:a".to_string(),
+            "-map".to_string(),
+            "2:s".to_string(),
+        ]);
+    }
+
+    args.extend([
         "-c:v".to_string(),
         "copy".to_string(),
         "-c:a".to_string(),
         "aac".to_string(),
+    ]);
+
+    if let Some(track) = subtitles {
+        args.extend([
+            "-c:s".to_string(),
+            "mov_text".to_string(),
+            "-metadata:s:s:0".to_string(),
+            format!("language={}", track.language),
+        ]);
+    }
+
+    args.extend([
         "-shortest".to_string(),
         output_path.to_string_lossy().to_string(),
-    ]
+    ]);
+    args
 }
 
 // ---------------------------------------------------------------------------
@@ -745,7 +1081,7 @@ mod tests {
         use crate::timeline::Timeline;
 
         let tl = Timeline::default();
-        let clip = assemble_audio_track(&tl, 48000, None).unwrap();
+        let clip = assemble_audio_track(&tl, 48000, None, None);
         assert_eq!(clip.data.len(), 0);
         assert!((clip.duration() - 0.0).abs() < f64::EPSILON);
         assert_eq!(clip.sample_rate, 48000);
@@ -760,9 +1096,10 @@ mod tests {
         tl.add_segment(Segment::Narration {
             text: "Hello world".into(),
             duration: 2.0,
+            voice: None,
         });
 
-        let clip = assemble_audio_track(&tl, 48000, None).unwrap();
+        let clip = assemble_audio_track(&tl, 48000, None, None);
         assert!((clip.duration() - 2.0).abs() < 1e-10);
         assert_eq!(clip.data.len(), 96000); // 2.0 * 48000
     }
@@ -775,6 +1112,7 @@ mod tests {
         tl.add_segment(Segment::Narration {
             text: "Intro".into(),
             duration: 3.0,
+            voice: None,
         });
         tl.add_segment(Segment::Silence { duration: 0.5 });
         tl.add_segment(Segment::Animation {
@@ -786,7 +1124,7 @@ mod tests {
             duration: 2.0,
         });
 
-        let clip = assemble_audio_track(&tl, 48000, None).unwrap();
+        let clip = assemble_audio_track(&tl, 48000, None, None);
 
         // Total duration should match timeline
         let expected_duration = 3.0 + 0.5 + 1.0 + 2.0;
@@ -802,7 +1140,7 @@ mod tests {
         tl.add_segment(Segment::Silence { duration: 1.0 });
         tl.add_segment(Segment::Silence { duration: 0.5 });
 
-        let clip = assemble_audio_track(&tl, 48000, None).unwrap();
+        let clip = assemble_audio_track(&tl, 48000, None, None);
 
         // 1.0s = 48000 samples, 0.5s = 24000 samples
         assert_eq!(clip.data.len(), 48000 + 24000);
@@ -816,11 +1154,13 @@ mod tests {
         tl.add_segment(Segment::Narration {
             text: "Hello".into(),
             duration: 1.0,
+            voice: None,
         });
         tl.add_segment(Segment::Silence { duration: 0.5 });
         tl.add_segment(Segment::Narration {
             text: "World".into(),
             duration: 1.5,
+            voice: None,
         });
 
         // Create fake TTS clips at 48kHz
@@ -838,7 +1178,7 @@ mod tests {
         };
 
         let narration_clips = vec![clip1, clip2];
-        let result = assemble_audio_track(&tl, 48000, Some(&narration_clips)).unwrap();
+        let result = assemble_audio_track(&tl, 48000, Some(&narration_clips), None);
 
         // Total: 1.0s (narration) + 0.5s (silence) + 1.5s (narration) = 3.0s
         assert!((result.duration() - 3.0).abs() < 1e-10);
@@ -852,6 +1192,75 @@ mod tests {
         assert!((result.data[48000 + 24000] - 0.7).abs() < f32::EPSILON);
     }
 
+    #[test]
+    fn test_assemble_places_overlapping_narration_at_offset() {
+        use crate::timeline::{Segment, Timeline};
+
+        let mut tl = Timeline::new(30);
+        tl.add_segment(Segment::Silence { duration: 1.0 });
+        tl.add_segment(Segment::Animation {
+            name: "FadeIn".into(),
+            duration: 1.0,
+        });
+        tl.add_segment_with(
+            Segment::Narration {
+                text: "Over".into(),
+                duration: 0.5,
+                voice: None,
+            },
+            1,
+        );
+
+        let clip = AudioClip {
+            data: vec![0.5; 24000], // 0.5s at 48kHz
+            duration: 0.5,
+            sample_rate: 48000,
+            channels: 1,
+        };
+        let result = assemble_audio_track(&tl, 48000, Some(&[clip]), None);
+
+        // Narration overlaps the animation, so the track is 2.0s, not 2.5s.
+        assert_eq!(result.data.len(), 96000);
+        assert!((result.duration() - 2.0).abs() < 1e-10);
+        // Narration starts at 1.0s, together with the animation.
+        assert!((result.data[47999] - 0.0).abs() < f32::EPSILON);
+        assert!((result.data[48000] - 0.5).abs() < f32::EPSILON);
+        assert!((result.data[48000 + 23999] - 0.5).abs() < f32::EPSILON);
+        assert!((result.data[48000 + 24000] - 0.0).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn test_assemble_normalises_clip_format() {
+        use crate::timeline::{Segment, Timeline};
+
+        let mut tl = Timeline::new(30);
+        tl.add_segment(Segment::Narration {
+            text: "Hello".into(),
+            duration: 1.0,
+            voice: None,
+        });
+        tl.add_segment(Segment::Narration {
+            text: "World".into(),
+            duration: 1.0,
+            voice: None,
+        });
+        let mut low_rate = AudioClip::silence(1.0, 24000);
+        low_rate.data.fill(0.5);
+        let stereo = AudioClip {
+            data: [0.25, 0.75].repeat(48000),
+            duration: 1.0,
+            sample_rate: 48000,
+            channels: 2,
+        };
+
+        let result = assemble_audio_track(&tl, 48000, Some(&[low_rate, stereo]), None);
+        assert_eq!(result.sample_rate, 48000);
+        assert_eq!(result.channels, 1);
+        assert_eq!(result.data.len(), 96000);
+        assert!((result.data[24000] - 0.5).abs() < 1e-3);
+        assert!((result.data[72000] - 0.5).abs() < 1e-6);
+    }
+
     #[test]
     fn test_assemble_narration_clips_none_fallback() {
         use crate::timeline::{Segment, Timeline};
@@ -860,15 +1269,123 @@ mod tests {
         tl.add_segment(Segment::Narration {
             text: "Hello".into(),
             duration: 1.0,
+            voice: None,
         });
 
         // None means silence fallback
-        let clip = assemble_audio_track(&tl, 48000, None).unwrap();
+        let clip = assemble_audio_track(&tl, 48000, None, None);
         assert!((clip.duration() - 1.0).abs() < 1e-10);
         // All samples should be zero (silence)
         assert!(clip.data.iter().all(|&s| s == 0.0));
     }
 
+    #[test]
+    fn test_assemble_places_clip_audio() {
+        use crate::timeline::{Segment, Timeline};
+
+        let mut tl = Timeline::new(30);
+        tl.add_segment(Segment::Silence { duration: 0.5 });
+        tl.add_segment(Segment::Clip {
+            path: "broll.mp4".into(),
+            duration: 1.0,
+        });
+        // Narration over the clip, anchored to its start.
+        tl.add_segment_with(
+            Segment::Narration {
+                text: "Look".into(),
+                duration: 0.5,
+                voice: None,
+            },
+            1,
+        );
+
+        let mut clip_audio = AudioClip::silence(1.0, 48000);
+        clip_audio.data.fill(0.25);
+        let mut narration = AudioClip::silence(0.5, 48000);
+        narration.data.fill(0.5);
+
+        let track =
+            assemble_audio_track(&tl, 48000, Some(&[narration]), Some(&[clip_audio]));
+
+        assert_eq!(track.data.len(), 72000);
+        assert!((track.data[23999] - 0.0).abs() < f32::EPSILON);
+        // Clip audio and narration are summed where they overlap.
+        assert!((track.data[24000] - 0.75).abs() < f32::EPSILON);
+        assert!((track.data[48000] - 0.25).abs() < f32::EPSILON);
+        assert!((track.data[71999] - 0.25).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn test_assemble_clip_audio_none_is_silent() {
+        use crate::timeline::{Segment, Timeline};
+
+        let mut tl = Timeline::new(30);
+        tl.add_segment(Segment::Clip {
+            path: "broll.mp4".into(),
+            duration: 1.0,
+        });
+
+        let track = assemble_audio_track(&tl, 48000, None, None);
+        assert!((track.duration() - 1.0).abs() < 1e-10);
+        assert!(track.data.iter().all(|&s| s == 0.0));
+    }
+
+    // -- clip helper tests -------------------------------------------------
+
+    #[test]
+    fn parse_probe_duration_values() {
+        assert_eq!(parse_probe_duration("12.480000\n"), Some(12.48));
+        assert_eq!(parse_probe_duration("N/A"), None);
+        assert_eq!(parse_probe_duration(""), None);
+        assert_eq!(parse_probe_duration("0.000000"), None);
+    }
+
+    #[test]
+    fn probe_duration_missing_file() {
+        let err = probe_duration(Path::new("/tmp/moron-missing-clip.mp4")).unwrap_err();
+        assert!(matches!(err, FfmpegError::InvalidInput { .. }));
+        assert!(format!("{err}").contains("clip does not exist"));
+    }
+
+    #[test]
+    fn error_display_probe_failed() {
+        let err = FfmpegError::ProbeFailed {
+            path: PathBuf::from("clip.mp4"),
+            message: "Invalid data found".to_string(),
+        };
+        assert_eq!(format!("{err}"), "could not probe clip.mp4: Invalid data found");
+    }
+
+    #[test]
+    fn build_clip_frame_args_layout() {
+        let args = build_clip_frame_args(
+            Path::new("/tmp/clip.mp4"),
+            Path::new("/tmp/frames"),
+            45,
+            60,
+            30,
+            1280,
+            720,
+        );
+
+        assert_eq!(args[0], "-y");
+        assert_eq!(args[2], "/tmp/clip.mp4");
+        assert!(args[4].starts_with("fps=30,scale=1280:720:"));
+        assert!(args[4].contains("pad=1280:720:"));
+        assert_eq!(args[5..9], ["-frames:v", "60", "-start_number", "45"]);
+        assert_eq!(args[9], "/tmp/frames/frame_%06d.png");
+    }
+
+    #[test]
+    fn build_clip_audio_args_layout() {
+        let args = build_clip_audio_args(Path::new("/tmp/clip.mp4"), 2.5, 24000);
+        let joined = args.join(" ");
+        assert_eq!(
+            joined,
+            "-v error -i /tmp/clip.mp4 -t 2.5 -vn -ac 1 -ar 24000 -f f32le pipe:1"
+        );
+    }
+
     // -- build_mux_args tests ----------------------------------------------
 
     #[test]
@@ -876,6 +1393,7 @@ mod tests {
         let args = build_mux_args(
             Path::new("/tmp/video.mp4"),
             Path::new("/tmp/audio.wav"),
+            None,
             Path::new("/tmp/output.mp4"),
         );
 
@@ -899,6 +1417,45 @@ mod tests {
 
         // Output is the last argument
         assert_eq!(args.last().unwrap(), "/tmp/output.mp4");
+
+        // No subtitle stream without a track
+        assert!(!args.contains(&"-map".to_string()));
+        assert!(!args.contains(&"mov_text".to_string()));
+    }
+
+    #[test]
+    fn test_build_mux_args_with_subtitles() {
+        let track = SubtitleTrack::new("/tmp/captions.srt", "eng");
+        let args = build_mux_args(
+            Path::new("/tmp/video.mp4"),
+            Path::new("/tmp/audio.wav"),
+            Some(&track),
+            Path::new("/tmp/output.mp4"),
+        );
+
+        // Subtitles are the third input
+        let i_positions: Vec<usize> = args
+            .iter()
+            .enumerate()
+            .filter(|(_, a)| *a == "-i")
+            .map(|(i, _)| i)
+            .collect();
+        assert_eq!(i_positions.len(), 3);
+        assert_eq!(args[i_positions[2] + 1], "/tmp/captions.srt");
+
+        // Every stream is mapped, so the subtitle track is not dropped
+        let maps: Vec<&str> = args
+            .windows(2)
+            .filter(|w| w[0] == "-map")
+            .map(|w| w[1].as_str())
+            .collect();
+        assert_eq!(maps, vec!["0:v", "1:a", "2:s"]);
+
+        let codec = args.iter().position(|a| a == "-c:s").unwrap();
+        assert_eq!(args[codec + 1], "mov_text");
+        let metadata = args.iter().position(|a| a == "-metadata:s:s:0").unwrap();
+        assert_eq!(args[metadata + 1], "language=eng");
+        assert_eq!(args.last().unwrap(), "/tmp/output.mp4");
     }
 
     // -- mux_audio input validation tests ----------------------------------
@@ -932,4 +1489,20 @@ mod tests {
 
         fs::remove_file(&video_tmp).ok();
     }
+
+    #[test]
+    fn test_mux_streams_missing_subtitles() {
+        let video_tmp = std::env::temp_dir().join("moron_test_mux_subs_video.mp4");
+        let audio_tmp = std::env::temp_dir().join("moron_test_mux_subs_audio.wav");
+        fs::write(&video_tmp, [0u8; 4]).unwrap();
+        fs::write(&audio_tmp, [0u8; 4]).unwrap();
+
+        let track = SubtitleTrack::new("/nonexistent/captions.srt", "eng");
+        let result = mux_streams(&video_tmp, &audio_tmp, Some(&track), Path::new("/tmp/output.mp4"));
+        let msg = format!("{}", result.unwrap_err());
+        assert!(msg.contains("subtitle file does not exist"));
+
+        fs::remove_file(&video_tmp).ok();
+        fs::remove_file(&audio_tmp).ok();
+    }
 }
@@ -5,9 +5,11 @@
 
 use std::collections::HashMap;
 
+use moron_themes::Theme;
 use serde::{Deserialize, Serialize};
 
-use crate::facade::M;
+use crate::captions;
+use crate::facade::{Direction, TransitionRecord, M};
 use crate::timeline::Segment;
 
 // ---------------------------------------------------------------------------
@@ -34,6 +36,90 @@ pub enum ElementKind {
         /// Number of items in the list.
         count: usize,
     },
+    /// A custom visual rendered by a named component registered in the UI.
+    Component {
+        /// Registered component name (e.g. "architecture-diagram").
+        name: String,
+        /// Arbitrary JSON props passed through to the component unchanged.
+        props: serde_json::Value,
+    },
+    /// A side-by-side before/after comparison with two labelled panes.
+    Compare,
+    /// Callout text attached to another element by `M::focus`.
+    Annotation {
+        /// ID of the element this annotation points at.
+        target: u64,
+    },
+}
+
+// ---------------------------------------------------------------------------
+// Pane — one side of a Compare element
+// ---------------------------------------------------------------------------
+
+/// Content shown inside one pane of a Compare element.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", tag = "type")]
+pub enum PaneContent {
+    /// Plain text.
+    Text {
+        /// The text to display.
+        text: String,
+    },
+    /// A metric value with a directional indicator.
+    Metric {
+        /// The metric value (e.g. "120ms").
+        value: String,
+        /// Direction string: "up", "down", or "neutral".
+        direction: String,
+    },
+    /// A short list of items.
+    Steps {
+        /// The list items, in order.
+        items: Vec<String>,
+    },
+}
+
+/// One labelled side of a comparison, passed to [`M::compare`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Pane {
+    /// Heading shown above the pane (e.g. "Before").
+    pub label: String,
+    /// What the pane displays.
+    pub content: PaneContent,
+}
+
+impl Pane {
+    /// A pane showing plain text.
+    pub fn text(label: &str, text: &str) -> Self {
+        Self {
+            label: label.to_string(),
+            content: PaneContent::Text {
+                text: text.to_string(),
+            },
+        }
+    }
+
+    /// A pane showing a metric value with a directional indicator.
+    pub fn metric(label: &str, value: &str, direction: Direction) -> Self {
+        Self {
+            label: label.to_string(),
+            content: PaneContent::Metric {
+                value: value.to_string(),
+                direction: direction.as_str().to_string(),
+            },
+        }
+    }
+
+    /// A pane showing a list of items.
+    pub fn steps(label: &str, items: &[&str]) -> Self {
+        Self {
+            label: label.to_string(),
+            content: PaneContent::Steps {
+                items: items.iter().map(|s| s.to_string()).collect(),
+            },
+        }
+    }
 }
 
 // ---------------------------------------------------------------------------
@@ -75,6 +161,48 @@ impl ItemState {
     }
 }
 
+// ---------------------------------------------------------------------------
+// PaneState — per-pane visual snapshot (for Compare elements)
+// ---------------------------------------------------------------------------
+
+/// The visual state of one pane within a Compare element.
+///
+/// Carries the pane's content alongside individual transforms, so a Stagger
+/// can reveal the left pane before the right.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PaneState {
+    /// Heading shown above the pane.
+    pub label: String,
+    /// What the pane displays.
+    pub content: PaneContent,
+    /// Opacity (0.0 = transparent, 1.0 = fully opaque).
+    pub opacity: f64,
+    /// Horizontal translation in pixels.
+    pub translate_x: f64,
+    /// Vertical translation in pixels.
+    pub translate_y: f64,
+    /// Scale factor (1.0 = normal size).
+    pub scale: f64,
+    /// Rotation in degrees.
+    pub rotation: f64,
+}
+
+impl PaneState {
+    /// Create a PaneState with default (fully visible) transforms.
+    fn new(pane: Pane) -> Self {
+        Self {
+            label: pane.label,
+            content: pane.content,
+            opacity: 1.0,
+            translate_x: 0.0,
+            translate_y: 0.0,
+            scale: 1.0,
+            rotation: 0.0,
+        }
+    }
+}
+
 // ---------------------------------------------------------------------------
 // ElementState — per-element visual snapshot
 // ---------------------------------------------------------------------------
@@ -91,6 +219,8 @@ pub struct ElementState {
     pub content: String,
     /// List items with per-item visual state (non-empty only for Steps elements).
     pub items: Vec<ItemState>,
+    /// Comparison panes with per-pane visual state (non-empty only for Compare elements).
+    pub panes: Vec<PaneState>,
     /// Whether this element is currently visible.
     pub visible: bool,
     /// Opacity (0.0 = transparent, 1.0 = fully opaque).
@@ -122,6 +252,43 @@ pub struct ThemeState {
     pub css_properties: HashMap<String, String>,
 }
 
+// ---------------------------------------------------------------------------
+// FocusState — the element currently drawn attention to
+// ---------------------------------------------------------------------------
+
+/// The active focus highlight, set while an `M::focus` annotation is on screen.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FocusState {
+    /// ID of the focused element (where the highlight ring is drawn).
+    pub target_id: u64,
+    /// ID of the annotation element carrying the callout text.
+    pub annotation_id: u64,
+    /// Highlight strength: 0.0 = off, 1.0 = fully on. Drives the ring.
+    pub intensity: f64,
+}
+
+// ---------------------------------------------------------------------------
+// CaptionState — the subtitle on screen
+// ---------------------------------------------------------------------------
+
+/// The caption shown for burned-in subtitles.
+///
+/// Only computed when the build enables
+/// [`burn_in_captions`](crate::build::BuildConfig::burn_in_captions).
+/// Narration is split into cues with the build's
+/// [`caption_config`](crate::build::BuildConfig::caption_config), so the
+/// on-screen text matches the `.srt` and the subtitle track.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CaptionState {
+    /// The current caption, wrapped into lines.
+    pub lines: Vec<String>,
+    /// Index of the word being spoken, counting words across all lines.
+    /// `None` before the first word of the caption is spoken.
+    pub active_word: Option<usize>,
+}
+
 // ---------------------------------------------------------------------------
 // FrameState — the complete visual state at a timestamp
 // ---------------------------------------------------------------------------
@@ -145,8 +312,12 @@ pub struct FrameState {
     pub elements: Vec<ElementState>,
     /// Text of the currently active narration, if any.
     pub active_narration: Option<String>,
+    /// The caption for the active narration, if burned-in captions are on.
+    pub captions: Option<CaptionState>,
     /// Current theme as CSS custom properties.
     pub theme: ThemeState,
+    /// The active focus highlight, if any.
+    pub focus: Option<FocusState>,
 }
 
 // ---------------------------------------------------------------------------
@@ -158,7 +329,8 @@ pub struct FrameState {
 /// Walks the scene's element records and timeline to determine:
 /// - Which elements are visible (created_at <= time)
 /// - Default visual properties for visible elements
-/// - Active narration text from overlapping narration segments
+/// - Active narration text from overlapping narration segments, and the
+///   caption cue and word being spoken within it
 /// - Current theme as CSS properties
 pub fn compute_frame_state(m: &M, time: f64) -> FrameState {
     let total_duration = m.timeline().total_duration();
@@ -178,6 +350,7 @@ pub fn compute_frame_state(m: &M, time: f64) -> FrameState {
                 kind: rec.kind.clone(),
                 content: rec.content.clone(),
                 items: rec.items.iter().map(|t| ItemState::new(t.clone())).collect(),
+                panes: rec.panes.iter().cloned().map(PaneState::new).collect(),
                 visible,
                 // Default visual state — overwritten by apply_animations() below.
                 opacity: if visible { 1.0 } else { 0.0 },
@@ -190,25 +363,50 @@ pub fn compute_frame_state(m: &M, time: f64) -> FrameState {
         })
         .collect();
 
+    // A slide transition in progress shows its incoming set from the start
+    // of the transition window, alongside the outgoing set.
+    let transition = active_transition(m, clamped_time);
+    if let Some(active) = &transition {
+        for el in elements.iter_mut().filter(|el| active.incoming_ids.contains(&el.id)) {
+            el.visible = true;
+            el.opacity = 1.0;
+            el.scale = 1.0;
+        }
+    }
+
     // Apply animation technique outputs to elements.
     apply_animations(m, clamped_time, &mut elements);
 
+    // Dim everything but the focused element and its annotation.
+    let focus = apply_focus(m, clamped_time, &mut elements);
+
+    // Transform both sides of the transition on top of their own animations.
+    if let Some(active) = &transition {
+        apply_transition(active, &mut elements);
+    }
+
     // Assign vertical layout positions based on visible element composition.
-    assign_layout_positions(&mut elements);
+    // Outgoing elements keep the layout they had before the transition began.
+    let outgoing_ids = transition
+        .as_ref()
+        .map(|active| active.record.outgoing_ids.as_slice())
+        .unwrap_or_default();
+    assign_layout_positions(&mut elements, outgoing_ids);
 
     // Find active narration: any Narration segment overlapping the current time.
     // Use a tiny epsilon window around the current time for point-in-time query.
     let epsilon = 1.0 / fps as f64 / 2.0;
     let active_narration = find_active_narration(m, clamped_time, epsilon);
+    let captions = caption_at(m, clamped_time, epsilon);
 
-    // Build theme state from current theme.
-    let theme_ref = m.current_theme();
+    // Build theme state from the theme active at this time.
+    let theme_ref = theme_at(m, clamped_time);
     let css_props: HashMap<String, String> = theme_ref
         .to_css_properties()
         .into_iter()
         .collect();
     let theme = ThemeState {
-        name: theme_ref.name.clone(),
+        name: theme_ref.name,
         css_properties: css_props,
     };
 
@@ -219,14 +417,43 @@ pub fn compute_frame_state(m: &M, time: f64) -> FrameState {
         fps,
         elements,
         active_narration,
+        captions,
         theme,
+        focus,
     }
 }
 
+/// The theme in effect at `time`.
+///
+/// Uses the latest theme change at or before `time`, blended from the theme
+/// before it while its fade is in progress. Before the first change the
+/// default theme applies.
+fn theme_at(m: &M, time: f64) -> Theme {
+    let themes = m.themes();
+    let Some(i) = themes.iter().rposition(|rec| rec.changed_at <= time) else {
+        return Theme::default();
+    };
+
+    let rec = &themes[i];
+    let elapsed = time - rec.changed_at;
+    if rec.fade <= 0.0 || elapsed >= rec.fade {
+        return rec.theme.clone();
+    }
+
+    let previous = i
+        .checked_sub(1)
+        .map(|j| themes[j].theme.clone())
+        .unwrap_or_default();
+    previous.blend(&rec.theme, elapsed / rec.fade)
+}
+
 /// Apply animation technique outputs to elements based on the current time.
 ///
 /// For each animation record, computes the animation's progress within its
 /// timeline segment and applies the technique's visual output to target elements.
+/// Exit animations are applied after all others. An exit that uses an entrance
+/// technique (anything but an [`is_exit`](moron_techniques::Technique::is_exit)
+/// technique such as `FadeOut`) plays it in reverse.
 fn apply_animations(m: &M, time: f64, elements: &mut [ElementState]) {
     // Build ID → index lookup for O(1) element access.
     let id_to_index: HashMap<u64, usize> = elements
@@ -236,16 +463,23 @@ fn apply_animations(m: &M, time: f64, elements: &mut [ElementState]) {
         .collect();
 
     let segments = m.timeline().segments();
+    let starts = m.timeline().segment_starts();
 
-    for record in m.animations() {
-        // Compute the animation segment's absolute time window.
-        let seg_start: f64 = segments[..record.segment_index]
-            .iter()
-            .map(|s| s.duration())
-            .sum();
+    // Exit animations run last so they take over from entrance and emphasis
+    // state. Exits and emphasis only apply once their window has started.
+    let (regular, exits): (Vec<_>, Vec<_>) = m.animations().iter().partition(|r| !r.exit);
+
+    for record in regular.into_iter().chain(exits) {
+        // Compute the animation segment's absolute time window. Segments may
+        // overlap, so the start comes from the timeline rather than a running sum.
+        let seg_start = starts[record.segment_index];
         let seg_duration = segments[record.segment_index].duration();
         let seg_end = seg_start + seg_duration;
 
+        if (record.exit || record.emphasis) && time < seg_start {
+            continue;
+        }
+
         // Compute progress: 0.0 before start, 1.0 after end, linear during.
         let progress = if time < seg_start {
             0.0
@@ -254,6 +488,11 @@ fn apply_animations(m: &M, time: f64, elements: &mut [ElementState]) {
         } else {
             (time - seg_start) / seg_duration
         };
+        let progress = if record.exit && !record.technique.is_exit() {
+            1.0 - progress
+        } else {
+            progress
+        };
 
         // Apply to each target element (only if visible).
         for &target_id in &record.target_ids {
@@ -261,7 +500,18 @@ fn apply_animations(m: &M, time: f64, elements: &mut [ElementState]) {
                 && elements[idx].visible
             {
                 let item_count = elements[idx].items.len();
-                if item_count > 0 {
+                let pane_count = elements[idx].panes.len();
+                if pane_count > 0 {
+                    // Compare element: apply per-pane transforms, left to right.
+                    let outputs = record.technique.apply_items(pane_count, progress);
+                    for (pane, output) in elements[idx].panes.iter_mut().zip(outputs) {
+                        pane.opacity = output.opacity;
+                        pane.translate_x = output.translate_x;
+                        pane.translate_y = output.translate_y;
+                        pane.scale = output.scale;
+                        pane.rotation = output.rotation;
+                    }
+                } else if item_count > 0 {
                     // Steps element: apply per-item transforms via apply_items().
                     let outputs = record.technique.apply_items(item_count, progress);
                     for (i, item_output) in outputs.into_iter().enumerate() {
@@ -289,6 +539,111 @@ fn apply_animations(m: &M, time: f64, elements: &mut [ElementState]) {
     }
 }
 
+/// A slide transition whose window contains the current time.
+struct ActiveTransition<'a> {
+    record: &'a TransitionRecord,
+    /// Linear progress through the transition window (0.0 to 1.0).
+    progress: f64,
+    /// Elements that appear during the transition window.
+    incoming_ids: Vec<u64>,
+}
+
+/// Find the transition in progress at `time`, if any.
+fn active_transition(m: &M, time: f64) -> Option<ActiveTransition<'_>> {
+    let timeline = m.timeline();
+    let starts = timeline.segment_starts();
+
+    m.transitions().iter().find_map(|record| {
+        let seg_start = starts[record.segment_index];
+        let seg_duration = timeline.segments()[record.segment_index].duration();
+        let seg_end = seg_start + seg_duration;
+        if time < seg_start || time >= seg_end {
+            return None;
+        }
+
+        // Anything that appears from the start of the window up to its end,
+        // whether created right after the transition or alongside it in a
+        // `together` block, slides in with it.
+        let incoming_ids = m
+            .elements()
+            .iter()
+            .filter(|rec| {
+                rec.created_at >= seg_start
+                    && rec.created_at <= seg_end + 1e-9
+                    && rec.ended_at.is_none_or(|end| end > seg_start)
+                    && !record.outgoing_ids.contains(&rec.id)
+            })
+            .map(|rec| rec.id)
+            .collect();
+
+        Some(ActiveTransition {
+            record,
+            progress: (time - seg_start) / seg_duration,
+            incoming_ids,
+        })
+    })
+}
+
+/// Compose the transition's outgoing and incoming outputs onto element transforms.
+///
+/// Opacity and scale multiply, translation and rotation add, so an element
+/// still finishing its own animation keeps moving while the transition runs.
+fn apply_transition(active: &ActiveTransition<'_>, elements: &mut [ElementState]) {
+    let transition = &active.record.transition;
+    for el in elements.iter_mut().filter(|el| el.visible) {
+        let output = if active.record.outgoing_ids.contains(&el.id) {
+            transition.outgoing(active.progress)
+        } else if active.incoming_ids.contains(&el.id) {
+            transition.incoming(active.progress)
+        } else {
+            continue;
+        };
+        el.opacity *= output.opacity;
+        el.scale *= output.scale;
+        el.translate_x += output.translate_x;
+        el.translate_y += output.translate_y;
+        el.rotation += output.rotation;
+    }
+}
+
+/// Apply the most recent active focus, returning its state for the frame.
+///
+/// A focus is active while both its annotation and its target are visible.
+/// Other visible elements dim as the highlight ramps up, and the annotation
+/// fades in with it.
+fn apply_focus(m: &M, time: f64, elements: &mut [ElementState]) -> Option<FocusState> {
+    let is_visible = |elements: &[ElementState], id: u64| {
+        elements.iter().any(|el| el.id == id && el.visible)
+    };
+    let record = m.focuses().iter().rev().find(|rec| {
+        is_visible(elements, rec.annotation_id) && is_visible(elements, rec.target_id)
+    })?;
+
+    let seg_start = m.timeline().segment_start(record.segment_index)?;
+    let seg_duration = m.timeline().segments()[record.segment_index].duration();
+    let progress = if seg_duration <= 0.0 {
+        1.0
+    } else {
+        ((time - seg_start) / seg_duration).clamp(0.0, 1.0)
+    };
+    let intensity = record.highlight.intensity(progress);
+    let dim = record.highlight.dim_opacity(progress);
+
+    for el in elements.iter_mut().filter(|el| el.visible) {
+        if el.id == record.annotation_id {
+            el.opacity *= intensity;
+        } else if el.id != record.target_id {
+            el.opacity *= dim;
+        }
+    }
+
+    Some(FocusState {
+        target_id: record.target_id,
+        annotation_id: record.annotation_id,
+        intensity,
+    })
+}
+
 /// Returns true for element kinds that act as headers (Title, Section).
 fn is_header(kind: &ElementKind) -> bool {
     matches!(kind, ElementKind::Title | ElementKind::Section)
@@ -299,20 +654,41 @@ fn is_header(kind: &ElementKind) -> bool {
 /// Headers (Title, Section) sort before bodies (Show, Steps, Metric).
 /// Within each group, creation order is preserved. Positions are assigned:
 /// - 1 visible element: centered at 0.5
-/// - 2 visible elements: 0.3 and 0.65
+/// - 2 visible elements: 0.3 and 0.65 (0.15 and 0.58 when one is a Compare)
 /// - 3+ visible elements: evenly spaced from 0.2 to 0.8
-fn assign_layout_positions(elements: &mut [ElementState]) {
-    // Collect indices of visible elements, partitioned into headers-first order.
+///
+/// Elements in `outgoing_ids` (leaving in a slide transition) are laid out as
+/// their own group, so the outgoing and incoming slides don't crowd each other.
+/// Annotations are placed level with the element they point at.
+fn assign_layout_positions(elements: &mut [ElementState], outgoing_ids: &[u64]) {
+    let (outgoing, staying): (Vec<usize>, Vec<usize>) = (0..elements.len())
+        .filter(|&i| elements[i].visible)
+        .filter(|&i| !matches!(elements[i].kind, ElementKind::Annotation { .. }))
+        .partition(|&i| outgoing_ids.contains(&elements[i].id));
+    assign_layout_group(elements, &staying);
+    assign_layout_group(elements, &outgoing);
+
+    // Annotations don't take a slot; they sit level with their target.
+    for i in 0..elements.len() {
+        if let ElementKind::Annotation { target } = elements[i].kind
+            && let Some(target_y) = elements.iter().find(|el| el.id == target).map(|el| el.layout_y)
+        {
+            elements[i].layout_y = target_y;
+        }
+    }
+}
+
+/// Assign layout positions to one group of visible elements.
+fn assign_layout_group(elements: &mut [ElementState], group: &[usize]) {
+    // Partition the group into headers-first order.
     let mut header_indices: Vec<usize> = Vec::new();
     let mut body_indices: Vec<usize> = Vec::new();
 
-    for (i, el) in elements.iter().enumerate() {
-        if el.visible {
-            if is_header(&el.kind) {
-                header_indices.push(i);
-            } else {
-                body_indices.push(i);
-            }
+    for &i in group {
+        if is_header(&elements[i].kind) {
+            header_indices.push(i);
+        } else {
+            body_indices.push(i);
         }
     }
 
@@ -326,9 +702,16 @@ fn assign_layout_positions(elements: &mut [ElementState]) {
         return;
     }
 
+    // A Compare element is tall, so a header above it sits higher and the
+    // comparison takes the space below.
+    let has_compare = sorted_indices
+        .iter()
+        .any(|&i| elements[i].kind == ElementKind::Compare);
+
     // Compute layout_y positions based on visible element count.
     let positions: Vec<f64> = match count {
         1 => vec![0.5],
+        2 if has_compare => vec![0.15, 0.58],
         2 => vec![0.3, 0.65],
         n => {
             // Evenly distribute from 0.2 to 0.8.
@@ -360,6 +743,35 @@ fn find_active_narration(m: &M, time: f64, epsilon: f64) -> Option<String> {
     None
 }
 
+/// The caption cue on screen at `time` within the active narration, or
+/// `None` if burned-in captions are off.
+///
+/// A cue stays up until the next one starts, so pauses between cues inside
+/// a narration don't blank the subtitle.
+fn caption_at(m: &M, time: f64, epsilon: f64) -> Option<CaptionState> {
+    let config = m.burned_in_captions()?;
+    let (index, (text, start, duration)) = captions::narrations(m)
+        .into_iter()
+        .enumerate()
+        .find(|(_, (_, start, duration))| *start < time + epsilon && start + duration > time)?;
+
+    let words = captions::narration_words(m, index, text, start, duration);
+    let mut current = None;
+    for (cue, range) in captions::split_cues(&words, config) {
+        if current.is_some() && cue.start > time {
+            break;
+        }
+        current = Some((cue, range));
+    }
+
+    let (cue, range) = current?;
+    let spoken = words[range].iter().filter(|w| w.start <= time).count();
+    Some(CaptionState {
+        lines: cue.lines,
+        active_word: spoken.checked_sub(1),
+    })
+}
+
 // ---------------------------------------------------------------------------
 // Tests
 // ---------------------------------------------------------------------------
@@ -367,7 +779,9 @@ fn find_active_narration(m: &M, time: f64, epsilon: f64) -> Option<String> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::captions::CaptionConfig;
     use crate::facade::{Direction, M};
+    use crate::frame::Pane;
 
     #[test]
     fn empty_scene_frame_state() {
@@ -446,6 +860,87 @@ mod tests {
         assert!(fs.active_narration.is_none());
     }
 
+    #[test]
+    fn captions_track_spoken_word() {
+        let mut m = M::new();
+        m.set_burned_in_captions(Some(CaptionConfig::default()));
+        m.narrate("Hello brave new world"); // 4 words, ~1.6s, 0.4s each
+
+        let fs = compute_frame_state(&m, 0.1);
+        let captions = fs.captions.unwrap();
+        assert_eq!(captions.lines, vec!["Hello brave new world"]);
+        assert_eq!(captions.active_word, Some(0));
+
+        let fs = compute_frame_state(&m, 1.3);
+        assert_eq!(fs.captions.unwrap().active_word, Some(3));
+    }
+
+    #[test]
+    fn captions_advance_between_cues() {
+        let mut m = M::new();
+        m.set_burned_in_captions(Some(CaptionConfig::default()));
+        m.narrate("First one. Second one.");
+
+        let first = compute_frame_state(&m, 0.1).captions.unwrap();
+        assert_eq!(first.lines, vec!["First one."]);
+
+        let second = compute_frame_state(&m, 1.4).captions.unwrap();
+        assert_eq!(second.lines, vec!["Second one."]);
+        assert_eq!(second.active_word, Some(1));
+    }
+
+    #[test]
+    fn captions_follow_word_timings() {
+        use moron_voice::WordTiming;
+
+        let mut m = M::new();
+        m.set_burned_in_captions(Some(CaptionConfig::default()));
+        m.narrate("one two");
+        m.resolve_narration_durations(&[2.0]).unwrap();
+        m.resolve_word_timings(vec![vec![
+            WordTiming { word: "one".into(), start: 0.5, end: 0.8 },
+            WordTiming { word: "two".into(), start: 1.5, end: 1.8 },
+        ]])
+        .unwrap();
+
+        // Before the first word, the caption is up but nothing is spoken.
+        assert_eq!(compute_frame_state(&m, 0.2).captions.unwrap().active_word, None);
+        assert_eq!(compute_frame_state(&m, 1.0).captions.unwrap().active_word, Some(0));
+        assert_eq!(compute_frame_state(&m, 1.6).captions.unwrap().active_word, Some(1));
+    }
+
+    #[test]
+    fn no_captions_during_silence() {
+        let mut m = M::new();
+        m.set_burned_in_captions(Some(CaptionConfig::default()));
+        m.narrate("Hello world");
+        m.wait(2.0);
+
+        assert!(compute_frame_state(&m, 1.5).captions.is_none());
+        let value = serde_json::to_value(compute_frame_state(&m, 0.0)).unwrap();
+        assert!(value["captions"].get("activeWord").is_some());
+    }
+
+    #[test]
+    fn captions_are_off_by_default() {
+        let mut m = M::new();
+        m.narrate("Hello world");
+        assert!(compute_frame_state(&m, 0.1).captions.is_none());
+    }
+
+    #[test]
+    fn captions_use_configured_rules() {
+        let mut m = M::new();
+        m.set_burned_in_captions(Some(CaptionConfig::default().with_max_line_chars(11)));
+        m.narrate("Hello brave new world");
+
+        let captions = compute_frame_state(&m, 1.3).captions.unwrap();
+        assert_eq!(captions.lines, vec!["Hello brave", "new world"]);
+        assert_eq!(captions.active_word, Some(3));
+        let cues = captions::caption_cues(&m, m.burned_in_captions().unwrap());
+        assert_eq!(cues[0].lines, captions.lines);
+    }
+
     #[test]
     fn frame_state_serializes_to_json() {
         let mut m = M::new();
@@ -523,6 +1018,49 @@ mod tests {
         assert_eq!(item_texts, vec!["one", "two", "three"]);
     }
 
+    #[test]
+    fn component_element_serializes_name_and_props() {
+        let mut m = M::new();
+        m.diagram(
+            "flow-chart",
+            serde_json::json!({ "nodes": ["parse", "render"], "animated": true }),
+        );
+
+        let fs = compute_frame_state(&m, 0.0);
+        let json = serde_json::to_value(&fs).unwrap();
+        let kind = &json["elements"][0]["kind"];
+        assert_eq!(kind["type"], "component");
+        assert_eq!(kind["name"], "flow-chart");
+        assert_eq!(kind["props"]["nodes"][1], "render");
+        assert_eq!(kind["props"]["animated"], true);
+        assert_eq!(json["elements"][0]["content"], "flow-chart");
+
+        // Round-trips back into the same kind.
+        let back: FrameState = serde_json::from_value(json).unwrap();
+        assert_eq!(back.elements[0].kind, fs.elements[0].kind);
+    }
+
+    #[test]
+    fn compare_element_serializes_panes() {
+        let mut m = M::new();
+        m.compare(Pane::text("Before", "manual"), Pane::text("After", "automated"));
+
+        let fs = compute_frame_state(&m, 0.0);
+        let json = serde_json::to_value(&fs).unwrap();
+        let el = &json["elements"][0];
+        assert_eq!(el["kind"]["type"], "compare");
+        assert_eq!(el["panes"][0]["label"], "Before");
+        assert_eq!(el["panes"][0]["content"]["type"], "text");
+        assert_eq!(el["panes"][1]["content"]["text"], "automated");
+        assert_eq!(el["panes"][1]["translateX"], 0.0);
+        assert!(el["items"].as_array().unwrap().is_empty());
+
+        // Non-compare elements carry no panes.
+        m.show("other");
+        let fs = compute_frame_state(&m, 0.0);
+        assert!(fs.elements[1].panes.is_empty());
+    }
+
     #[test]
     fn frame_number_computed_correctly() {
         let mut m = M::new();
@@ -639,6 +1177,59 @@ mod tests {
         assert_ne!(json_dark, json_light, "Theme JSON must differ between dark and light");
     }
 
+    #[test]
+    fn theme_change_applies_from_its_timeline_position() {
+        use moron_themes::Theme;
+
+        let mut m = M::new();
+        m.title("Dark part");
+        m.wait(1.0);
+        m.theme(Theme::light());
+        m.wait(1.0);
+
+        assert_eq!(compute_frame_state(&m, 0.5).theme.name, "moron-dark");
+        assert_eq!(compute_frame_state(&m, 1.0).theme.name, "moron-light");
+        assert_eq!(compute_frame_state(&m, 1.5).theme.name, "moron-light");
+    }
+
+    #[test]
+    fn theme_fade_blends_colors() {
+        use moron_themes::Theme;
+
+        let mut m = M::new();
+        m.wait(1.0);
+        m.theme_fade(Theme::light(), 1.0);
+        m.wait(2.0);
+
+        let dark_bg = Theme::default().colors.bg_primary;
+        let light_bg = Theme::light().colors.bg_primary;
+        let bg = |t: f64| {
+            compute_frame_state(&m, t).theme.css_properties["--moron-bg-primary"].clone()
+        };
+
+        assert_eq!(bg(0.5), dark_bg);
+        let mid = bg(1.5);
+        assert_ne!(mid, dark_bg);
+        assert_ne!(mid, light_bg);
+        assert_eq!(bg(2.0), light_bg);
+        assert_eq!(bg(2.5), light_bg);
+    }
+
+    #[test]
+    fn theme_change_inside_together_starts_with_block() {
+        use moron_themes::Theme;
+
+        let mut m = M::new();
+        m.wait(1.0);
+        m.together(|m| {
+            m.wait(2.0);
+            m.theme(Theme::light());
+        });
+
+        assert_eq!(compute_frame_state(&m, 0.5).theme.name, "moron-dark");
+        assert_eq!(compute_frame_state(&m, 1.0).theme.name, "moron-light");
+    }
+
     // -- Layout tests ------------------------------------------------------
 
     #[test]
@@ -889,6 +1480,328 @@ mod tests {
         assert!((fs.elements[0].opacity - 1.0).abs() < f64::EPSILON);
     }
 
+    #[test]
+    fn together_animations_run_simultaneously() {
+        use moron_techniques::FadeIn;
+
+        let mut m = M::new();
+        let a = m.title("A");
+        let b = m.show("B");
+        m.together(|m| {
+            m.play_on(a, FadeIn { duration: 1.0 }).unwrap();
+            m.play_on(b, FadeIn { duration: 2.0 }).unwrap();
+            m.narrate("Both at once");
+        });
+
+        // The block lasts as long as its longest segment.
+        assert!((m.timeline().total_duration() - 2.0).abs() < f64::EPSILON);
+
+        // At t=0.5 both animations are in progress.
+        let fs = compute_frame_state(&m, 0.5);
+        assert!((fs.elements[0].opacity - 0.5).abs() < f64::EPSILON);
+        assert!((fs.elements[1].opacity - 0.25).abs() < f64::EPSILON);
+        assert_eq!(fs.active_narration.as_deref(), Some("Both at once"));
+
+        // At t=1.5 the first has finished, the second is still running.
+        let fs = compute_frame_state(&m, 1.5);
+        assert!((fs.elements[0].opacity - 1.0).abs() < f64::EPSILON);
+        assert!((fs.elements[1].opacity - 0.75).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn exit_animation_plays_before_element_ends() {
+        use moron_techniques::{FadeIn, FadeOut};
+
+        let mut m = M::new();
+        let title = m.title("Bye");
+        m.play(FadeIn { duration: 1.0 });   // [0.0, 1.0)
+        m.wait(1.0);                        // [1.0, 2.0)
+        m.hide_with(title, FadeOut { duration: 1.0 }).unwrap(); // [2.0, 3.0)
+        m.show("Next");
+
+        // Entrance is mid-way; the pending exit must not override it.
+        let fs = compute_frame_state(&m, 0.5);
+        assert!((fs.elements[0].opacity - 0.5).abs() < f64::EPSILON);
+
+        // Holding between entrance and exit.
+        let fs = compute_frame_state(&m, 1.5);
+        assert!((fs.elements[0].opacity - 1.0).abs() < f64::EPSILON);
+
+        // Exit in progress: still visible, fading out.
+        let fs = compute_frame_state(&m, 2.25);
+        assert!(fs.elements[0].visible);
+        assert!((fs.elements[0].opacity - 0.75).abs() < f64::EPSILON);
+        assert!(!fs.elements[1].visible);
+
+        // After the exit window the element is gone.
+        let fs = compute_frame_state(&m, 3.0);
+        assert!(!fs.elements[0].visible);
+        assert!(fs.elements[1].visible);
+    }
+
+    #[test]
+    fn entrance_technique_as_exit_plays_in_reverse() {
+        use moron_techniques::{FadeIn, FadeUp};
+
+        let mut m = M::new();
+        let title = m.title("Bye");
+        m.play(FadeIn { duration: 1.0 }); // [0.0, 1.0)
+        m.hide_with(title, FadeUp { duration: 1.0, distance: 40.0 }).unwrap(); // [1.0, 2.0)
+
+        // The exit starts from the resting state...
+        let fs = compute_frame_state(&m, 1.0);
+        assert!((fs.elements[0].opacity - 1.0).abs() < f64::EPSILON);
+        assert!(fs.elements[0].translate_y.abs() < f64::EPSILON);
+
+        // ...and heads back to where the entrance started.
+        let fs = compute_frame_state(&m, 1.75);
+        assert!((fs.elements[0].opacity - 0.25).abs() < 1e-9);
+        assert!((fs.elements[0].translate_y - 30.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn hide_leaves_other_elements_on_screen() {
+        let mut m = M::new();
+        let a = m.title("A");
+        m.show("B");
+        m.wait(1.0);
+        m.hide(a).unwrap();
+        m.wait(1.0);
+
+        let fs = compute_frame_state(&m, 1.5);
+        assert!(!fs.elements[0].visible);
+        assert!(fs.elements[1].visible);
+        // Only B remains, so it is centered on its own.
+        assert!((fs.elements[1].layout_y - 0.5).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn layout_title_plus_compare() {
+        let mut m = M::new();
+        m.title("Results");
+        m.compare(Pane::text("Before", "a"), Pane::text("After", "b"));
+
+        let fs = compute_frame_state(&m, 0.0);
+        assert!((fs.elements[0].layout_y - 0.15).abs() < f64::EPSILON);
+        assert!((fs.elements[1].layout_y - 0.58).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn stagger_reveals_left_pane_before_right() {
+        use moron_techniques::{FadeIn, Stagger};
+
+        let mut m = M::new();
+        m.compare(Pane::text("Before", "a"), Pane::text("After", "b"));
+        // Each pane fades over 1.0s, the right one starts 1.0s later.
+        m.play(Stagger::new(FadeIn { duration: 1.0 }).with_delay(1.0).with_count(2));
+
+        let fs = compute_frame_state(&m, 0.5);
+        let panes = &fs.elements[0].panes;
+        assert!((panes[0].opacity - 0.5).abs() < 1e-10);
+        assert!((panes[1].opacity - 0.0).abs() < 1e-10);
+
+        let fs = compute_frame_state(&m, 1.5);
+        let panes = &fs.elements[0].panes;
+        assert!((panes[0].opacity - 1.0).abs() < 1e-10);
+        assert!((panes[1].opacity - 0.5).abs() < 1e-10);
+
+        // Element-level transforms stay at defaults.
+        assert!((fs.elements[0].opacity - 1.0).abs() < f64::EPSILON);
+    }
+
+    // -- Focus tests -------------------------------------------------------
+
+    #[test]
+    fn focus_dims_others_and_exposes_state() {
+        use moron_techniques::Highlight;
+
+        let mut m = M::new();
+        let title = m.title("Pipeline");
+        let body = m.show("Stage 2 is slow");
+        let note = m
+            .focus_with(body, "Look here", Highlight { duration: 1.0, dim: 0.4, scale: 1.0 })
+            .unwrap();
+        m.wait(1.0);
+
+        // Fully ramped up.
+        let fs = compute_frame_state(&m, 1.5);
+        let focus = fs.focus.clone().expect("focus should be active");
+        assert_eq!(focus.target_id, body.0);
+        assert_eq!(focus.annotation_id, note.0);
+        assert!((focus.intensity - 1.0).abs() < f64::EPSILON);
+        assert!((fs.elements[title.0 as usize].opacity - 0.4).abs() < 1e-10);
+        assert!((fs.elements[body.0 as usize].opacity - 1.0).abs() < f64::EPSILON);
+        assert!((fs.elements[note.0 as usize].opacity - 1.0).abs() < f64::EPSILON);
+
+        // At the very start nothing is dimmed yet.
+        let fs = compute_frame_state(&m, 0.0);
+        assert!((fs.focus.unwrap().intensity - 0.0).abs() < f64::EPSILON);
+        assert!((fs.elements[title.0 as usize].opacity - 1.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn focus_waits_for_entrance_to_finish() {
+        use moron_techniques::FadeIn;
+
+        let mut m = M::new();
+        let title = m.title("A");
+        m.play(FadeIn { duration: 1.0 }); // [0.0, 1.0)
+        m.focus(title, "callout").unwrap(); // starts at 1.0
+
+        // The pending highlight must not snap the title to full opacity.
+        let fs = compute_frame_state(&m, 0.5);
+        assert!(fs.focus.is_none());
+        assert!((fs.elements[0].opacity - 0.5).abs() < f64::EPSILON);
+
+        let fs = compute_frame_state(&m, 1.0);
+        assert!(fs.focus.is_some());
+        assert!((fs.elements[0].opacity - 1.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn focus_ends_with_annotation() {
+        let mut m = M::new();
+        let title = m.title("A");
+        let note = m.focus(title, "callout").unwrap();
+        m.wait(1.0);
+        m.hide(note).unwrap();
+        m.wait(1.0);
+
+        assert!(compute_frame_state(&m, 0.9).focus.is_some());
+        let fs = compute_frame_state(&m, 2.0);
+        assert!(fs.focus.is_none());
+        assert!((fs.elements[0].opacity - 1.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn annotation_sits_level_with_target() {
+        let mut m = M::new();
+        m.title("Header");
+        let body = m.show("Body");
+        m.focus(body, "callout").unwrap();
+
+        let fs = compute_frame_state(&m, 0.0);
+        // The annotation doesn't push the title + body layout apart.
+        assert!((fs.elements[0].layout_y - 0.3).abs() < f64::EPSILON);
+        assert!((fs.elements[1].layout_y - 0.65).abs() < f64::EPSILON);
+        assert!((fs.elements[2].layout_y - 0.65).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn focus_serializes_camel_case() {
+        let mut m = M::new();
+        let t = m.title("A");
+        m.focus(t, "note").unwrap();
+
+        let json = serde_json::to_value(compute_frame_state(&m, 0.0)).unwrap();
+        assert_eq!(json["focus"]["targetId"], 0);
+        assert_eq!(json["focus"]["annotationId"], 1);
+        assert_eq!(json["elements"][1]["kind"]["type"], "annotation");
+        assert_eq!(json["elements"][1]["kind"]["target"], 0);
+
+        let empty = serde_json::to_value(compute_frame_state(&M::new(), 0.0)).unwrap();
+        assert!(empty["focus"].is_null());
+    }
+
+    // -- Transition tests --------------------------------------------------
+
+    #[test]
+    fn transition_shows_both_sets() {
+        use moron_techniques::Crossfade;
+
+        let mut m = M::new();
+        m.title("Old");
+        m.wait(1.0);                               // [0.0, 1.0)
+        m.clear_with(Crossfade { duration: 1.0 }); // [1.0, 2.0)
+        m.title("New");
+        m.wait(1.0);                               // [2.0, 3.0)
+
+        // Before the transition only the old slide is visible.
+        let fs = compute_frame_state(&m, 0.5);
+        assert!(fs.elements[0].visible);
+        assert!(!fs.elements[1].visible);
+
+        // Mid-transition both are visible and cross-faded.
+        let fs = compute_frame_state(&m, 1.25);
+        assert!(fs.elements[0].visible);
+        assert!(fs.elements[1].visible);
+        assert!((fs.elements[0].opacity - 0.75).abs() < f64::EPSILON);
+        assert!((fs.elements[1].opacity - 0.25).abs() < f64::EPSILON);
+
+        // After the transition only the new slide remains, at rest.
+        let fs = compute_frame_state(&m, 2.5);
+        assert!(!fs.elements[0].visible);
+        assert!(fs.elements[1].visible);
+        assert!((fs.elements[1].opacity - 1.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn transition_brings_in_elements_created_together() {
+        use moron_techniques::Crossfade;
+
+        let mut m = M::new();
+        m.title("Old");
+        m.wait(1.0); // [0.0, 1.0)
+        m.together(|m| {
+            m.clear_with(Crossfade { duration: 1.0 }); // [1.0, 2.0)
+            m.title("New");
+            m.show("Body");
+            m.wait(1.5); // [1.0, 2.5)
+        });
+        m.show("Later");
+        m.wait(1.0); // [2.5, 3.5)
+
+        let fs = compute_frame_state(&m, 1.25);
+        assert!((fs.elements[0].opacity - 0.75).abs() < f64::EPSILON);
+        for el in &fs.elements[1..3] {
+            assert!(el.visible);
+            assert!((el.opacity - 0.25).abs() < f64::EPSILON);
+        }
+        // Created after the window: not part of the incoming slide.
+        assert!(!fs.elements[3].visible);
+    }
+
+    #[test]
+    fn transition_lays_out_each_set_separately() {
+        use moron_techniques::Push;
+
+        let mut m = M::new();
+        m.title("Old title");
+        m.show("Old body");
+        m.clear_with(Push { duration: 1.0, distance: 1000.0 });
+        m.title("New title");
+
+        let fs = compute_frame_state(&m, 0.5);
+        // Outgoing pair keeps its two-element layout.
+        assert!((fs.elements[0].layout_y - 0.3).abs() < f64::EPSILON);
+        assert!((fs.elements[1].layout_y - 0.65).abs() < f64::EPSILON);
+        // Incoming title is alone on its slide.
+        assert!((fs.elements[2].layout_y - 0.5).abs() < f64::EPSILON);
+
+        assert!((fs.elements[0].translate_x + 500.0).abs() < f64::EPSILON);
+        assert!((fs.elements[2].translate_x - 500.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn transition_composes_with_entrance_animation() {
+        use moron_techniques::{Crossfade, FadeIn};
+
+        let mut m = M::new();
+        m.title("Old");
+        m.clear_with(Crossfade { duration: 1.0 }); // [0.0, 1.0)
+        m.title("New");
+        m.play(FadeIn { duration: 1.0 });          // [1.0, 2.0)
+
+        // The pending entrance keeps the incoming title hidden during the
+        // transition instead of being overridden by it.
+        let fs = compute_frame_state(&m, 0.5);
+        assert!(fs.elements[1].visible);
+        assert!((fs.elements[1].opacity - 0.0).abs() < f64::EPSILON);
+
+        let fs = compute_frame_state(&m, 1.5);
+        assert!((fs.elements[1].opacity - 0.5).abs() < f64::EPSILON);
+    }
+
     // -- Per-item animation tests (Stagger + Steps) -------------------------
 
     #[test]
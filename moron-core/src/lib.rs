@@ -10,21 +10,25 @@ pub mod facade;
 pub mod camera;
 pub mod frame;
 pub mod build;
+pub mod soundtrack;
+pub mod captions;
 pub mod demo;
 pub mod what_is_moron;
 
 // Re-export key types at crate root for convenience.
-pub use facade::{Direction, Element, M, ResolveDurationError, Scene, BEAT_DURATION, BREATH_DURATION};
-pub use frame::{compute_frame_state, ElementKind, ElementState, FrameState, ItemState, ThemeState};
+pub use facade::{Direction, Element, ElementError, M, ResolveDurationError, Scene, BEAT_DURATION, BREATH_DURATION};
+pub use frame::{compute_frame_state, CaptionState, ElementKind, ElementState, FocusState, FrameState, ItemState, Pane, PaneContent, PaneState, ThemeState};
 pub use moron_techniques::{Ease, Technique};
 pub use moron_themes::Theme;
-pub use moron_voice::Voice;
+pub use moron_voice::{create_backend, AudioClip, BackendConfig, BackendError, CachingBackend, Ducking, KokoroVoice, Voice, VoiceBackend, VoiceBackendType, WordTiming, DEFAULT_SAMPLE_RATE};
 pub use renderer::{render, RenderConfig, RenderError, RenderProgress, RenderResult};
-pub use ffmpeg::{assemble_audio_track, detect_ffmpeg, encode as encode_video, mux_audio, EncodeConfig, FfmpegError};
-pub use build::{build_video, BuildConfig, BuildError, BuildProgress, BuildResult};
+pub use ffmpeg::{assemble_audio_track, detect_ffmpeg, encode as encode_video, mux_audio, mux_streams, probe_duration, EncodeConfig, FfmpegError, SubtitleTrack};
+pub use build::{build_video, BuildConfig, BuildError, BuildProgress, BuildResult, VoiceResolver};
 pub use demo::DemoScene;
 pub use what_is_moron::WhatIsMoronScene;
 pub use timeline::{Segment, Timeline, TimelineBuilder};
+pub use soundtrack::{Music, Sfx};
+pub use captions::{CaptionConfig, CaptionCue, SubtitleConfig};
 
 /// Prelude module: import everything a scene author needs in one line.
 ///
@@ -34,13 +38,15 @@ pub use timeline::{Segment, Timeline, TimelineBuilder};
 pub mod prelude {
     pub use moron_techniques::{Ease, Technique, TechniqueExt};
     pub use moron_themes::Theme;
-    pub use moron_voice::Voice;
-    pub use crate::facade::{Direction, Element, M, ResolveDurationError, Scene};
-    pub use crate::frame::{compute_frame_state, ElementKind, ElementState, FrameState, ThemeState};
+    pub use moron_voice::{Ducking, KokoroVoice, Voice};
+    pub use crate::facade::{Direction, Element, ElementError, M, ResolveDurationError, Scene};
+    pub use crate::frame::{compute_frame_state, CaptionState, ElementKind, ElementState, FocusState, FrameState, Pane, PaneContent, ThemeState};
     pub use crate::renderer::{render, RenderConfig, RenderError, RenderProgress, RenderResult};
-    pub use crate::ffmpeg::{assemble_audio_track, detect_ffmpeg, encode as encode_video, mux_audio, EncodeConfig, FfmpegError};
-    pub use crate::build::{build_video, BuildConfig, BuildError, BuildProgress, BuildResult};
+    pub use crate::ffmpeg::{assemble_audio_track, detect_ffmpeg, encode as encode_video, mux_audio, mux_streams, EncodeConfig, FfmpegError, SubtitleTrack};
+    pub use crate::build::{build_video, BuildConfig, BuildError, BuildProgress, BuildResult, VoiceResolver};
     pub use crate::demo::DemoScene;
     pub use crate::what_is_moron::WhatIsMoronScene;
     pub use crate::timeline::{Segment, Timeline, TimelineBuilder};
+    pub use crate::soundtrack::{Music, Sfx};
+    pub use crate::captions::{CaptionConfig, CaptionCue, SubtitleConfig};
 }
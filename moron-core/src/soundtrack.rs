@@ -0,0 +1,435 @@
+//! Soundtrack: background music and sound effects laid over the narration
+//! track.
+//!
+//! Music ([`M::music`](crate::facade::M::music)) and sound effects
+//! ([`M::sfx`](crate::facade::M::sfx)) are recorded on their own track,
+//! separate from the timeline segments, so they never change scene timing.
+//! At build time each piece of music is decoded, looped or trimmed to the
+//! time it plays for, and mixed under the narration, ducking while anyone
+//! speaks. Sound effects play once at their cue point; each distinct file is
+//! decoded only once however often it is cued.
+
+use std::collections::HashMap;
+use std::ops::Range;
+use std::path::{Path, PathBuf};
+
+use moron_voice::{AudioClip, Ducking, MixClip, Mixer};
+
+use crate::facade::M;
+use crate::ffmpeg::{self, FfmpegError};
+use crate::timeline::{Segment, Timeline};
+
+// ---------------------------------------------------------------------------
+// Music
+// ---------------------------------------------------------------------------
+
+/// A background music bed and how it should play.
+///
+/// ```
+/// use moron_core::{Ducking, Music};
+///
+/// let music = Music::new("assets/bed.mp3")
+///     .with_volume(0.4)
+///     .with_loop(true)
+///     .with_fade_in(1.0)
+///     .with_fade_out(3.0)
+///     .with_ducking(Ducking::default().with_level(0.2));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Music {
+    /// Audio file to play (any format FFmpeg can decode).
+    pub path: PathBuf,
+    /// Linear gain (1.0 = the file's own level).
+    pub volume: f32,
+    /// Repeat the file until the music stops, instead of playing it once.
+    pub looped: bool,
+    /// Seconds to fade in from silence.
+    pub fade_in: f64,
+    /// Seconds to fade out before the music stops.
+    pub fade_out: f64,
+    /// How the music is lowered under narration; `None` keeps it level.
+    pub ducking: Option<Ducking>,
+}
+
+impl Music {
+    /// Play `path` once at full volume, ducked under narration with the
+    /// default [`Ducking`] settings.
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self {
+            path: path.into(),
+            volume: 1.0,
+            looped: false,
+            fade_in: 0.0,
+            fade_out: 0.0,
+            ducking: Some(Ducking::default()),
+        }
+    }
+
+    /// Set the linear gain.
+    pub fn with_volume(mut self, volume: f32) -> Self {
+        self.volume = volume.max(0.0);
+        self
+    }
+
+    /// Set whether the file repeats until the music stops.
+    pub fn with_loop(mut self, looped: bool) -> Self {
+        self.looped = looped;
+        self
+    }
+
+    /// Set the fade-in length in seconds.
+    pub fn with_fade_in(mut self, seconds: f64) -> Self {
+        self.fade_in = seconds.max(0.0);
+        self
+    }
+
+    /// Set the fade-out length in seconds.
+    pub fn with_fade_out(mut self, seconds: f64) -> Self {
+        self.fade_out = seconds.max(0.0);
+        self
+    }
+
+    /// Set how the music is lowered under narration. The time ranges are
+    /// filled in from the timeline at build time.
+    pub fn with_ducking(mut self, ducking: Ducking) -> Self {
+        self.ducking = Some(ducking);
+        self
+    }
+
+    /// Keep the music level under narration.
+    pub fn without_ducking(mut self) -> Self {
+        self.ducking = None;
+        self
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Sfx
+// ---------------------------------------------------------------------------
+
+/// A short sound effect.
+///
+/// ```
+/// use moron_core::Sfx;
+///
+/// let whoosh = Sfx::new("assets/whoosh.wav").with_volume(0.6);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Sfx {
+    /// Audio file to play (any format FFmpeg can decode).
+    pub path: PathBuf,
+    /// Linear gain (1.0 = the file's own level).
+    pub volume: f32,
+}
+
+impl Sfx {
+    /// Play `path` once at full volume.
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self {
+            path: path.into(),
+            volume: 1.0,
+        }
+    }
+
+    /// Set the linear gain.
+    pub fn with_volume(mut self, volume: f32) -> Self {
+        self.volume = volume.max(0.0);
+        self
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Mixing
+// ---------------------------------------------------------------------------
+
+/// Decode the scene's music and sound effects and mix them into `track`.
+///
+/// # Errors
+///
+/// Returns [`FfmpegError`] if an audio file cannot be probed or decoded.
+pub(crate) fn add_soundtrack(track: AudioClip, m: &M) -> Result<AudioClip, FfmpegError> {
+    let timeline = m.timeline();
+    let speech = narration_ranges(timeline);
+    let sample_rate = track.sample_rate;
+
+    let mut layers = Vec::new();
+    for (music, span) in music_spans(m, timeline.total_duration()) {
+        let length = span.end - span.start;
+        let audio = decode_music(&music.path, music.looped, length, sample_rate)?;
+        layers.push(music_layer(music, span.start, audio, &speech));
+    }
+    let mut decoded: HashMap<&Path, AudioClip> = HashMap::new();
+    for rec in m.sfx_records() {
+        let path = rec.sfx.path.as_path();
+        if !decoded.contains_key(path) {
+            decoded.insert(path, decode_sfx(path, sample_rate)?);
+        }
+        layers.push(sfx_layer(&rec.sfx, rec.cued_at, decoded[path].clone()));
+    }
+
+    Ok(mix_layers(track, layers))
+}
+
+/// When each recorded piece of music plays: from where it was recorded to
+/// where the next piece starts, or the end of the timeline.
+fn music_spans(m: &M, total: f64) -> Vec<(&Music, Range<f64>)> {
+    let records = m.music_records();
+    records
+        .iter()
+        .enumerate()
+        .map(|(i, rec)| {
+            let end = records.get(i + 1).map_or(total, |next| next.started_at);
+            (&rec.music, rec.started_at..end.max(rec.started_at))
+        })
+        .filter(|(_, span)| span.end > span.start)
+        .collect()
+}
+
+/// Decode up to `length` seconds of a music file, repeating it to fill the
+/// whole length when `looped`.
+fn decode_music(
+    path: &Path,
+    looped: bool,
+    length: f64,
+    sample_rate: u32,
+) -> Result<AudioClip, FfmpegError> {
+    let natural = ffmpeg::probe_duration(path)?.min(length);
+    if natural <= 0.0 {
+        return Ok(AudioClip::silence(0.0, sample_rate));
+    }
+    let audio = ffmpeg::extract_clip_audio(path, natural, sample_rate)?;
+    Ok(if looped { audio.looped(length) } else { audio })
+}
+
+/// Decode a sound effect to mono at `sample_rate`.
+///
+/// WAV files are read directly; other formats, and WAV encodings the
+/// built-in decoder doesn't handle, go through FFmpeg.
+fn decode_sfx(path: &Path, sample_rate: u32) -> Result<AudioClip, FfmpegError> {
+    let is_wav = path
+        .extension()
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("wav"));
+    if is_wav && let Ok(clip) = AudioClip::from_wav_file(path) {
+        return Ok(clip.to_mono().resample(sample_rate));
+    }
+    let length = ffmpeg::probe_duration(path)?;
+    ffmpeg::extract_clip_audio(path, length, sample_rate)
+}
+
+/// Time ranges covered by narration segments.
+fn narration_ranges(timeline: &Timeline) -> Vec<Range<f64>> {
+    timeline
+        .segments()
+        .iter()
+        .zip(timeline.segment_starts())
+        .filter(|(seg, _)| matches!(seg, Segment::Narration { .. }))
+        .map(|(seg, start)| start..start + seg.duration())
+        .collect()
+}
+
+/// Place decoded music starting at `start`, ducked under `speech`.
+fn music_layer(music: &Music, start: f64, audio: AudioClip, speech: &[Range<f64>]) -> MixClip {
+    let layer = MixClip::new(audio, start)
+        .with_gain(music.volume)
+        .with_fade_in(music.fade_in)
+        .with_fade_out(music.fade_out);
+    match &music.ducking {
+        Some(ducking) => layer.with_ducking(ducking.clone().with_ranges(speech.to_vec())),
+        None => layer,
+    }
+}
+
+/// Place a decoded sound effect at `at`.
+fn sfx_layer(sfx: &Sfx, at: f64, audio: AudioClip) -> MixClip {
+    MixClip::new(audio, at).with_gain(sfx.volume)
+}
+
+/// Mix `layers` into `track`. The result keeps the track's length.
+fn mix_layers(track: AudioClip, layers: Vec<MixClip>) -> AudioClip {
+    if layers.is_empty() {
+        return track;
+    }
+
+    let frames = track.data.len() / usize::from(track.channels.max(1));
+    let sample_rate = track.sample_rate;
+    let mut mixer = Mixer::mono(sample_rate);
+    mixer.add(MixClip::new(track, 0.0));
+    for layer in layers {
+        mixer.add(layer);
+    }
+
+    let mut mixed = mixer.mix();
+    mixed.data.truncate(frames);
+    mixed.duration = frames as f64 / f64::from(sample_rate);
+    mixed
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A mono clip at 1 kHz with every sample set to `value`.
+    fn constant(value: f32, seconds: f64) -> AudioClip {
+        let mut clip = AudioClip::silence(seconds, 1000);
+        clip.data.fill(value);
+        clip
+    }
+
+    #[test]
+    fn music_builder() {
+        let music = Music::new("bed.mp3")
+            .with_volume(0.5)
+            .with_loop(true)
+            .with_fade_in(1.0)
+            .with_fade_out(-2.0)
+            .without_ducking();
+        assert_eq!(music.path, PathBuf::from("bed.mp3"));
+        assert_eq!(music.volume, 0.5);
+        assert!(music.looped);
+        assert_eq!(music.fade_in, 1.0);
+        assert_eq!(music.fade_out, 0.0);
+        assert_eq!(music.ducking, None);
+        assert_eq!(Music::new("bed.mp3").ducking, Some(Ducking::default()));
+    }
+
+    #[test]
+    fn spans_run_until_next_music_or_end() {
+        let mut m = M::new();
+        m.music("a.mp3");
+        m.wait(2.0);
+        m.music_with(Music::new("b.mp3").with_loop(true));
+        m.wait(1.0);
+
+        let spans = music_spans(&m, m.timeline().total_duration());
+        assert_eq!(spans.len(), 2);
+        assert_eq!(spans[0].0.path, PathBuf::from("a.mp3"));
+        assert_eq!(spans[0].1, 0.0..2.0);
+        assert_eq!(spans[1].1, 2.0..3.0);
+    }
+
+    #[test]
+    fn narration_ranges_follow_timeline() {
+        let mut m = M::new();
+        m.wait(1.0);
+        m.narrate("one two three four five");
+        m.wait(0.5);
+
+        let ranges = narration_ranges(m.timeline());
+        assert_eq!(ranges, vec![1.0..3.0]);
+    }
+
+    #[test]
+    fn music_start_follows_resolved_durations() {
+        let mut m = M::new();
+        m.narrate("one two three four five");
+        m.music("bed.mp3");
+        assert_eq!(m.music_records()[0].started_at, 2.0);
+
+        m.resolve_narration_durations(&[3.5]).unwrap();
+        assert_eq!(m.music_records()[0].started_at, 3.5);
+    }
+
+    #[test]
+    fn music_is_ducked_under_speech() {
+        let speech = AudioClip::silence(4.0, 1000);
+        let music = Music::new("bed.wav").with_volume(0.5).with_ducking(
+            Ducking::default()
+                .with_level(0.5)
+                .with_attack(0.0)
+                .with_release(0.0),
+        );
+
+        let layer = music_layer(&music, 0.0, constant(1.0, 10.0), &[1.0..2.0]);
+        let mixed = mix_layers(speech, vec![layer]);
+        // Cut to the narration track's length.
+        assert_eq!(mixed.data.len(), 4000);
+        assert!((mixed.duration() - 4.0).abs() < 1e-12);
+        assert_eq!(mixed.data[500], 0.5);
+        assert_eq!(mixed.data[1500], 0.25);
+        assert_eq!(mixed.data[2500], 0.5);
+    }
+
+    #[test]
+    fn music_without_ducking_sums_with_speech() {
+        let speech = constant(0.25, 2.0);
+        let music = Music::new("bed.wav").without_ducking();
+
+        let layer = music_layer(&music, 1.0, constant(0.5, 0.5), &[0.0..2.0]);
+        let mixed = mix_layers(speech, vec![layer]);
+        assert_eq!(mixed.data[500], 0.25);
+        assert_eq!(mixed.data[1200], 0.75);
+        assert_eq!(mixed.data[1800], 0.25);
+    }
+
+    #[test]
+    fn sfx_cues_follow_timeline() {
+        let mut m = M::new();
+        m.title("Hello");
+        m.sfx("pop.wav");
+        m.narrate("one two three four five");
+        m.play_with_sfx(
+            moron_techniques::FadeIn::default(),
+            Sfx::new("whoosh.wav").with_volume(0.5),
+        );
+        m.sfx("ding.wav");
+
+        let cues: Vec<_> = m
+            .sfx_records()
+            .iter()
+            .map(|rec| (rec.sfx.path.to_str().unwrap(), rec.cued_at))
+            .collect();
+        assert_eq!(cues[0], ("pop.wav", 0.0));
+        assert_eq!(cues[1], ("whoosh.wav", 2.0));
+        assert!(cues[2].1 > 2.0);
+        assert_eq!(m.sfx_records()[1].sfx.volume, 0.5);
+        // Cueing a sound never moves the timeline.
+        assert_eq!(m.timeline().segments().len(), 2);
+
+        m.resolve_narration_durations(&[1.0]).unwrap();
+        assert_eq!(m.sfx_records()[1].cued_at, 1.0);
+    }
+
+    #[test]
+    fn sfx_is_mixed_at_cue_and_cut_at_end() {
+        let track = AudioClip::silence(1.0, 1000);
+        let layer = sfx_layer(&Sfx::new("pop.wav").with_volume(0.5), 0.8, constant(1.0, 0.5));
+        let mixed = mix_layers(track, vec![layer]);
+
+        assert_eq!(mixed.data.len(), 1000);
+        assert_eq!(mixed.data[799], 0.0);
+        assert_eq!(mixed.data[800], 0.5);
+        assert_eq!(mixed.data[999], 0.5);
+    }
+
+    #[test]
+    fn wav_sfx_is_decoded_without_ffmpeg() {
+        let dir = std::env::temp_dir().join("moron_test_soundtrack_sfx");
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("click.wav");
+        let mut stereo = constant(0.5, 0.1).to_stereo().resample(2000);
+        stereo.data.fill(0.5);
+        std::fs::write(&path, stereo.to_wav_bytes()).unwrap();
+
+        let clip = decode_sfx(&path, 1000).unwrap();
+        assert_eq!(clip.channels, 1);
+        assert_eq!(clip.sample_rate, 1000);
+        assert_eq!(clip.data.len(), 100);
+
+        // The same file cued twice is mixed at both cues.
+        let mut m = M::new();
+        m.sfx(&path);
+        m.wait(0.5);
+        m.sfx(&path);
+        m.wait(0.5);
+        let mixed = add_soundtrack(AudioClip::silence(1.0, 1000), &m).unwrap();
+        assert!((mixed.data[50] - 0.5).abs() < 0.05);
+        assert_eq!(mixed.data[300], 0.0);
+        assert!((mixed.data[550] - 0.5).abs() < 0.05);
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
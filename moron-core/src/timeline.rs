@@ -3,9 +3,16 @@
 //! The `Timeline` is the backbone of video sequencing. It stores an ordered list
 //! of [`Segment`]s and provides methods to query total duration, map time to
 //! frame numbers, and find segments within a time range.
+//!
+//! Segments normally play back to back. A segment may instead be *anchored* to
+//! an earlier one, in which case both start at the same instant (or the
+//! anchored one a fixed offset later) and overlap. A sequential segment always
+//! starts once everything before it has finished.
 
 use std::path::PathBuf;
 
+use moron_voice::Voice;
+
 // ---------------------------------------------------------------------------
 // Segment
 // ---------------------------------------------------------------------------
@@ -14,7 +21,14 @@ use std::path::PathBuf;
 #[derive(Debug, Clone)]
 pub enum Segment {
     /// Narrated text synthesized via TTS.
-    Narration { text: String, duration: f64 },
+    ///
+    /// `voice` is the voice that was active when the narration was recorded;
+    /// `None` leaves the choice to the build's default backend.
+    Narration {
+        text: String,
+        duration: f64,
+        voice: Option<Voice>,
+    },
     /// A visual animation technique applied to elements.
     Animation { name: String, duration: f64 },
     /// A period of silence (no audio, visuals hold).
@@ -46,6 +60,12 @@ pub const DEFAULT_FPS: u32 = 30;
 #[derive(Debug, Clone)]
 pub struct Timeline {
     segments: Vec<Segment>,
+    /// Per-segment anchor: `Some(i)` starts together with segment `i` (plus
+    /// its offset), `None` starts after every preceding segment has ended.
+    anchors: Vec<Option<usize>>,
+    /// Per-segment delay (seconds) after the anchor's start. Always 0 for
+    /// sequential segments.
+    offsets: Vec<f64>,
     fps: u32,
 }
 
@@ -54,6 +74,8 @@ impl Timeline {
     pub fn new(fps: u32) -> Self {
         Self {
             segments: Vec::new(),
+            anchors: Vec::new(),
+            offsets: Vec::new(),
             fps,
         }
     }
@@ -61,6 +83,45 @@ impl Timeline {
     /// Append a segment to the end of the timeline.
     pub fn add_segment(&mut self, segment: Segment) {
         self.segments.push(segment);
+        self.anchors.push(None);
+        self.offsets.push(0.0);
+    }
+
+    /// Append a segment that starts at the same time as the segment at `anchor`.
+    ///
+    /// The two segments overlap instead of playing back to back. If `anchor`
+    /// is out of bounds the segment is appended sequentially.
+    pub fn add_segment_with(&mut self, segment: Segment, anchor: usize) {
+        self.add_segment_at(segment, anchor, 0.0);
+    }
+
+    /// Append a segment that starts `offset` seconds after the segment at
+    /// `anchor` starts.
+    ///
+    /// Negative offsets are clamped to 0. If `anchor` is out of bounds the
+    /// segment is appended sequentially and the offset is ignored.
+    pub fn add_segment_at(&mut self, segment: Segment, anchor: usize, offset: f64) {
+        let anchor = (anchor < self.segments.len()).then_some(anchor);
+        self.segments.push(segment);
+        self.anchors.push(anchor);
+        self.offsets.push(if anchor.is_some() { offset.max(0.0) } else { 0.0 });
+    }
+
+    /// Change how long after its anchor the segment at `index` starts.
+    ///
+    /// Returns `true` if the segment exists and is anchored, `false`
+    /// otherwise. Negative offsets are clamped to 0.
+    pub fn set_anchor_offset(&mut self, index: usize, offset: f64) -> bool {
+        if self.anchor(index).is_none() {
+            return false;
+        }
+        self.offsets[index] = offset.max(0.0);
+        true
+    }
+
+    /// The segment the one at `index` is anchored to, if any.
+    pub fn anchor(&self, index: usize) -> Option<usize> {
+        self.anchors.get(index).copied().flatten()
     }
 
     /// Get the ordered list of segments.
@@ -74,8 +135,57 @@ impl Timeline {
     }
 
     /// Total duration of the timeline in seconds.
+    ///
+    /// This is the latest end time of any segment, so overlapping segments
+    /// only count once.
     pub fn total_duration(&self) -> f64 {
-        self.segments.iter().map(|s| s.duration()).sum()
+        self.time_after(self.segments.len())
+    }
+
+    /// Start time of every segment, in timeline order.
+    pub fn segment_starts(&self) -> Vec<f64> {
+        let mut starts = Vec::with_capacity(self.segments.len());
+        let mut cursor = 0.0_f64;
+        for ((seg, anchor), offset) in self.segments.iter().zip(&self.anchors).zip(&self.offsets) {
+            let start = match anchor {
+                Some(i) => starts[*i] + offset,
+                None => cursor,
+            };
+            starts.push(start);
+            cursor = cursor.max(start + seg.duration());
+        }
+        starts
+    }
+
+    /// Start time of the segment at `index`, or `None` if out of bounds.
+    pub fn segment_start(&self, index: usize) -> Option<f64> {
+        self.segment_starts().get(index).copied()
+    }
+
+    /// Time at which the first `count` segments have all finished.
+    ///
+    /// This is where a sequential segment appended after them would start.
+    /// `count` larger than the number of segments is clamped.
+    pub fn time_after(&self, count: usize) -> f64 {
+        let count = count.min(self.segments.len());
+        self.segment_starts()
+            .iter()
+            .zip(&self.segments[..count])
+            .map(|(start, seg)| start + seg.duration())
+            .fold(0.0, f64::max)
+    }
+
+    /// [`time_after`](Self::time_after) for every `count` from 0 to the
+    /// number of segments, computed in a single pass.
+    pub fn times_after(&self) -> Vec<f64> {
+        let mut times = Vec::with_capacity(self.segments.len() + 1);
+        times.push(0.0);
+        let mut end = 0.0_f64;
+        for (start, seg) in self.segment_starts().iter().zip(&self.segments) {
+            end = end.max(start + seg.duration());
+            times.push(end);
+        }
+        times
     }
 
     /// Total number of frames at the timeline's FPS.
@@ -139,27 +249,13 @@ impl Timeline {
     /// Returns `(segment_start_time, &Segment)` pairs for every segment
     /// whose time span intersects the query range.
     pub fn segments_in_range(&self, start: f64, end: f64) -> Vec<(f64, &Segment)> {
-        let mut result = Vec::new();
-        let mut cursor = 0.0_f64;
-
-        for seg in &self.segments {
-            let seg_end = cursor + seg.duration();
-
-            // Segment [cursor, seg_end) overlaps [start, end) if:
-            // cursor < end AND seg_end > start
-            if cursor < end && seg_end > start {
-                result.push((cursor, seg));
-            }
-
-            cursor = seg_end;
-
-            // Early exit: past the query range
-            if cursor >= end {
-                break;
-            }
-        }
-
-        result
+        self.segment_starts()
+            .into_iter()
+            .zip(&self.segments)
+            // Segment [seg_start, seg_end) overlaps [start, end) if:
+            // seg_start < end AND seg_end > start
+            .filter(|(seg_start, seg)| *seg_start < end && seg_start + seg.duration() > start)
+            .collect()
     }
 }
 
@@ -200,6 +296,7 @@ impl TimelineBuilder {
         self.segments.push(Segment::Narration {
             text: text.to_string(),
             duration,
+            voice: None,
         });
         self
     }
@@ -230,10 +327,11 @@ impl TimelineBuilder {
 
     /// Consume the builder and produce a [`Timeline`].
     pub fn build(self) -> Timeline {
-        Timeline {
-            segments: self.segments,
-            fps: self.fps,
+        let mut timeline = Timeline::new(self.fps);
+        for seg in self.segments {
+            timeline.add_segment(seg);
         }
+        timeline
     }
 }
 
@@ -266,6 +364,7 @@ mod tests {
         tl.add_segment(Segment::Narration {
             text: "Hello".into(),
             duration: 2.0,
+            voice: None,
         });
         tl.add_segment(Segment::Silence { duration: 0.5 });
         tl.add_segment(Segment::Animation {
@@ -367,6 +466,7 @@ mod tests {
         let narr = Segment::Narration {
             text: "Hi".into(),
             duration: 1.5,
+            voice: None,
         };
         let anim = Segment::Animation {
             name: "Slide".into(),
@@ -401,11 +501,13 @@ mod tests {
         tl.add_segment(Segment::Narration {
             text: "Hi".into(),
             duration: 1.0,
+            voice: None,
         });
         tl.add_segment(Segment::Silence { duration: 0.5 });
         tl.add_segment(Segment::Narration {
             text: "Bye".into(),
             duration: 1.0,
+            voice: None,
         });
 
         // Total starts at 2.5
@@ -436,11 +538,13 @@ mod tests {
         tl.add_segment(Segment::Narration {
             text: "A".into(),
             duration: 1.0,
+            voice: None,
         }); // index 0
         tl.add_segment(Segment::Silence { duration: 0.5 }); // index 1
         tl.add_segment(Segment::Narration {
             text: "B".into(),
             duration: 1.0,
+            voice: None,
         }); // index 2
         tl.add_segment(Segment::Animation {
             name: "FadeIn".into(),
@@ -461,4 +565,111 @@ mod tests {
 
         assert!(tl.narration_indices().is_empty());
     }
+
+    #[test]
+    fn anchored_segments_overlap() {
+        let mut tl = Timeline::new(30);
+        tl.add_segment(Segment::Silence { duration: 1.0 });
+        tl.add_segment(Segment::Animation {
+            name: "FadeIn".into(),
+            duration: 0.5,
+        }); // index 1, starts at 1.0
+        tl.add_segment_with(
+            Segment::Narration {
+                text: "Meanwhile".into(),
+                duration: 2.0,
+                voice: None,
+            },
+            1,
+        ); // index 2, also starts at 1.0
+
+        assert_eq!(tl.anchor(1), None);
+        assert_eq!(tl.anchor(2), Some(1));
+        assert_eq!(tl.segment_starts(), vec![0.0, 1.0, 1.0]);
+        // The longest overlapping segment defines the end: 1.0 + 2.0
+        assert!((tl.total_duration() - 3.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn sequential_segment_waits_for_overlap_to_finish() {
+        let mut tl = Timeline::new(30);
+        tl.add_segment(Segment::Animation {
+            name: "FadeIn".into(),
+            duration: 0.5,
+        });
+        tl.add_segment_with(Segment::Silence { duration: 1.5 }, 0);
+        tl.add_segment(Segment::Silence { duration: 1.0 });
+
+        assert_eq!(tl.segment_start(2), Some(1.5));
+        assert_eq!(tl.segment_start(3), None);
+        assert!((tl.time_after(1) - 0.5).abs() < f64::EPSILON);
+        assert!((tl.time_after(2) - 1.5).abs() < f64::EPSILON);
+        assert!((tl.total_duration() - 2.5).abs() < f64::EPSILON);
+
+        let times = tl.times_after();
+        assert_eq!(times, vec![0.0, 0.5, 1.5, 2.5]);
+        for (count, time) in times.iter().enumerate() {
+            assert!((tl.time_after(count) - time).abs() < f64::EPSILON);
+        }
+    }
+
+    #[test]
+    fn anchored_segment_with_offset() {
+        let mut tl = Timeline::new(30);
+        tl.add_segment(Segment::Silence { duration: 1.0 });
+        tl.add_segment(Segment::Narration {
+            text: "A long sentence".into(),
+            duration: 2.0,
+            voice: None,
+        }); // index 1, starts at 1.0
+        tl.add_segment_at(
+            Segment::Animation {
+                name: "FadeIn".into(),
+                duration: 0.5,
+            },
+            1,
+            1.75,
+        ); // index 2, starts at 2.75 and ends after the narration
+        tl.add_segment(Segment::Silence { duration: 1.0 });
+
+        assert_eq!(tl.segment_starts(), vec![0.0, 1.0, 2.75, 3.25]);
+
+        assert!(tl.set_anchor_offset(2, 0.5));
+        assert_eq!(tl.segment_starts(), vec![0.0, 1.0, 1.5, 3.0]);
+
+        // Only anchored segments take an offset.
+        assert!(!tl.set_anchor_offset(1, 0.5));
+        assert!(!tl.set_anchor_offset(9, 0.5));
+    }
+
+    #[test]
+    fn add_segment_with_out_of_bounds_anchor_is_sequential() {
+        let mut tl = Timeline::new(30);
+        tl.add_segment(Segment::Silence { duration: 1.0 });
+        tl.add_segment_with(Segment::Silence { duration: 1.0 }, 7);
+
+        assert_eq!(tl.anchor(1), None);
+        assert!((tl.total_duration() - 2.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn segments_in_range_includes_overlapping_segments() {
+        let mut tl = Timeline::new(30);
+        tl.add_segment(Segment::Animation {
+            name: "FadeIn".into(),
+            duration: 1.0,
+        });
+        tl.add_segment_with(
+            Segment::Narration {
+                text: "Together".into(),
+                duration: 1.0,
+                voice: None,
+            },
+            0,
+        );
+
+        let hits = tl.segments_in_range(0.5, 0.6);
+        assert_eq!(hits.len(), 2);
+        assert!(hits.iter().all(|(start, _)| *start == 0.0));
+    }
 }
@@ -1 +1,98 @@
 //! Emphasis techniques: Pulse, Highlight, Underline, `ColorShift`, etc.
+
+use std::f64::consts::PI;
+
+use crate::technique::{ease, Ease, Technique, TechniqueOutput};
+
+/// Briefly grows an element and settles it back to its resting size.
+#[derive(Debug, Clone)]
+pub struct Pulse {
+    pub duration: f64,
+    /// Peak scale reached halfway through the pulse.
+    pub scale: f64,
+}
+
+impl Default for Pulse {
+    fn default() -> Self {
+        Self {
+            duration: 0.4,
+            scale: 1.1,
+        }
+    }
+}
+
+impl Technique for Pulse {
+    fn name(&self) -> &'static str {
+        "Pulse"
+    }
+
+    fn duration(&self) -> f64 {
+        self.duration
+    }
+
+    fn apply(&self, progress: f64) -> TechniqueOutput {
+        let bump = (PI * progress.clamp(0.0, 1.0)).sin();
+        TechniqueOutput {
+            scale: 1.0 + (self.scale - 1.0) * bump,
+            ..TechniqueOutput::default()
+        }
+    }
+}
+
+/// Draws attention to an element while the rest of the frame recedes.
+///
+/// Applied to the focused element it behaves like a gentle [`Pulse`]. The
+/// [`intensity`](Self::intensity) curve drives the highlight ring and how far
+/// other elements dim towards [`dim`](Self::dim).
+#[derive(Debug, Clone)]
+pub struct Highlight {
+    pub duration: f64,
+    /// Opacity other elements settle at while the highlight is on.
+    pub dim: f64,
+    /// Peak scale of the focused element's pulse.
+    pub scale: f64,
+}
+
+impl Default for Highlight {
+    fn default() -> Self {
+        Self {
+            duration: 0.6,
+            dim: 0.35,
+            scale: 1.05,
+        }
+    }
+}
+
+impl Highlight {
+    /// Strength of the highlight at the given progress (0.0 = off, 1.0 = full).
+    ///
+    /// Eases out so the ring snaps on and then settles.
+    #[must_use]
+    pub fn intensity(&self, progress: f64) -> f64 {
+        ease(Ease::EaseOut, progress)
+    }
+
+    /// Opacity multiplier for elements that are not in focus.
+    #[must_use]
+    pub fn dim_opacity(&self, progress: f64) -> f64 {
+        1.0 - (1.0 - self.dim) * self.intensity(progress)
+    }
+}
+
+impl Technique for Highlight {
+    fn name(&self) -> &'static str {
+        "Highlight"
+    }
+
+    fn duration(&self) -> f64 {
+        self.duration
+    }
+
+    fn apply(&self, progress: f64) -> TechniqueOutput {
+        let bump = (PI * progress.clamp(0.0, 1.0)).sin();
+        TechniqueOutput {
+            scale: 1.0 + (self.scale - 1.0) * bump,
+            ..TechniqueOutput::default()
+        }
+    }
+}
@@ -14,9 +14,11 @@ pub mod data;
 
 // Re-export core types for convenient access.
 pub use technique::{ease, Ease, Technique, TechniqueExt, TechniqueOutput, WithEase};
-pub use reveals::{FadeIn, FadeUp};
-pub use motion::{Scale, Slide};
+pub use reveals::{FadeIn, FadeOut, FadeUp};
+pub use motion::{Scale, Slide, SlideOut};
+pub use emphasis::{Highlight, Pulse};
 pub use staging::Stagger;
+pub use transitions::{Crossfade, Iris, MorphCut, Push, Transition};
 pub use data::CountUp;
 
 #[cfg(test)]
@@ -53,7 +55,9 @@ mod tests {
     fn all_techniques_have_names() {
         assert_eq!(FadeIn::default().name(), "FadeIn");
         assert_eq!(FadeUp::default().name(), "FadeUp");
+        assert_eq!(FadeOut::default().name(), "FadeOut");
         assert_eq!(Slide::default().name(), "Slide");
+        assert_eq!(SlideOut::default().name(), "SlideOut");
         assert_eq!(Scale::default().name(), "Scale");
         assert_eq!(CountUp::default().name(), "CountUp");
     }
@@ -98,6 +102,120 @@ mod tests {
         assert!((start.opacity - 1.0).abs() < f64::EPSILON); // opacity stays 1
     }
 
+    #[test]
+    fn fade_out_mirrors_fade_in() {
+        let fo = FadeOut::default();
+        assert!((fo.apply(0.0).opacity - 1.0).abs() < f64::EPSILON);
+        assert!((fo.apply(0.25).opacity - 0.75).abs() < f64::EPSILON);
+        assert!((fo.apply(1.0).opacity - 0.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn slide_out_translation() {
+        let s = SlideOut::default(); // offset_x = -100.0
+        let start = s.apply(0.0);
+        let end = s.apply(1.0);
+
+        assert!((start.translate_x - 0.0).abs() < f64::EPSILON);
+        assert!((start.opacity - 1.0).abs() < f64::EPSILON);
+        assert!((end.translate_x + 100.0).abs() < f64::EPSILON);
+        assert!((end.opacity - 0.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn exit_techniques_are_flagged() {
+        assert!(FadeOut::default().is_exit());
+        assert!(SlideOut::default().is_exit());
+        assert!(FadeOut::default().with_ease(Ease::EaseIn).is_exit());
+        assert!(!FadeIn::default().is_exit());
+        assert!(!Stagger::new(FadeUp::default()).is_exit());
+    }
+
+    #[test]
+    fn pulse_returns_to_rest() {
+        let p = Pulse::default(); // scale = 1.1
+        assert!((p.apply(0.0).scale - 1.0).abs() < 1e-10);
+        assert!((p.apply(0.5).scale - 1.1).abs() < 1e-10);
+        assert!((p.apply(1.0).scale - 1.0).abs() < 1e-10);
+        assert_eq!(p.name(), "Pulse");
+    }
+
+    #[test]
+    fn highlight_intensity_and_dimming() {
+        let h = Highlight::default(); // dim = 0.35
+        assert!((h.intensity(0.0) - 0.0).abs() < f64::EPSILON);
+        assert!((h.intensity(1.0) - 1.0).abs() < f64::EPSILON);
+        assert!(h.intensity(0.5) > 0.5, "eases out");
+
+        assert!((h.dim_opacity(0.0) - 1.0).abs() < f64::EPSILON);
+        assert!((h.dim_opacity(1.0) - 0.35).abs() < 1e-10);
+
+        // The focused element pulses and settles.
+        assert!((h.apply(1.0).scale - 1.0).abs() < 1e-10);
+        assert!(h.apply(0.5).scale > 1.0);
+        assert_eq!(h.name(), "Highlight");
+    }
+
+    #[test]
+    fn transitions_have_names() {
+        assert_eq!(Crossfade::default().name(), "Crossfade");
+        assert_eq!(Push::default().name(), "Push");
+        assert_eq!(Iris::default().name(), "Iris");
+        assert_eq!(MorphCut::default().name(), "MorphCut");
+    }
+
+    #[test]
+    fn transitions_rest_at_endpoints() {
+        let transitions: Vec<Box<dyn Transition>> = vec![
+            Box::new(Crossfade::default()),
+            Box::new(Push::default()),
+            Box::new(Iris::default()),
+            Box::new(MorphCut::default()),
+        ];
+        // Outgoing starts untouched, incoming ends untouched.
+        for t in &transitions {
+            assert_eq!(t.outgoing(0.0), TechniqueOutput::default(), "{}", t.name());
+            assert_eq!(t.incoming(1.0), TechniqueOutput::default(), "{}", t.name());
+        }
+    }
+
+    #[test]
+    fn crossfade_opacities_sum_to_one() {
+        let c = Crossfade::default();
+        for p in [0.0, 0.3, 0.5, 1.0] {
+            let total = c.outgoing(p).opacity + c.incoming(p).opacity;
+            assert!((total - 1.0).abs() < f64::EPSILON);
+        }
+    }
+
+    #[test]
+    fn push_moves_both_sets_together() {
+        let push = Push { duration: 1.0, distance: 1000.0 };
+        let out = push.outgoing(0.25);
+        let inc = push.incoming(0.25);
+        assert!((out.translate_x + 250.0).abs() < f64::EPSILON);
+        assert!((inc.translate_x - 750.0).abs() < f64::EPSILON);
+        // The gap between the two sets is always one full distance.
+        assert!((inc.translate_x - out.translate_x - 1000.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn iris_never_shows_both_sets() {
+        let iris = Iris::default();
+        assert!((iris.outgoing(0.25).scale - 0.5).abs() < f64::EPSILON);
+        assert!((iris.incoming(0.25).opacity - 0.0).abs() < f64::EPSILON);
+        assert!((iris.outgoing(0.75).opacity - 0.0).abs() < f64::EPSILON);
+        assert!((iris.incoming(0.75).scale - 0.5).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn morph_cut_zooms_through() {
+        let mc = MorphCut::default(); // zoom = 0.05
+        assert!((mc.outgoing(1.0).scale - 1.05).abs() < 1e-10);
+        assert!((mc.incoming(0.0).scale - 0.95).abs() < 1e-10);
+        assert!((mc.incoming(0.0).opacity - 0.0).abs() < f64::EPSILON);
+    }
+
     #[test]
     fn scale_interpolation() {
         let s = Scale::default(); // from=0.0, to=1.0
@@ -74,3 +74,47 @@ impl Technique for Scale {
         }
     }
 }
+
+/// Slides an element away from its resting position while fading it out.
+///
+/// An exit technique: progress 0.0 is the resting state, 1.0 is gone.
+#[derive(Debug, Clone)]
+pub struct SlideOut {
+    pub duration: f64,
+    pub offset_x: f64,
+    pub offset_y: f64,
+}
+
+impl Default for SlideOut {
+    fn default() -> Self {
+        Self {
+            duration: 0.5,
+            offset_x: -100.0,
+            offset_y: 0.0,
+        }
+    }
+}
+
+impl Technique for SlideOut {
+    fn name(&self) -> &'static str {
+        "SlideOut"
+    }
+
+    fn duration(&self) -> f64 {
+        self.duration
+    }
+
+    fn apply(&self, progress: f64) -> TechniqueOutput {
+        let p = progress.clamp(0.0, 1.0);
+        TechniqueOutput {
+            opacity: 1.0 - p,
+            translate_x: self.offset_x * p,
+            translate_y: self.offset_y * p,
+            ..TechniqueOutput::default()
+        }
+    }
+
+    fn is_exit(&self) -> bool {
+        true
+    }
+}
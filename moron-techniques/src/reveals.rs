@@ -65,3 +65,38 @@ impl Technique for FadeUp {
         }
     }
 }
+
+/// Fades an element out from fully opaque to transparent.
+///
+/// An exit technique: progress 0.0 is the resting state, 1.0 is gone.
+#[derive(Debug, Clone)]
+pub struct FadeOut {
+    pub duration: f64,
+}
+
+impl Default for FadeOut {
+    fn default() -> Self {
+        Self { duration: 0.5 }
+    }
+}
+
+impl Technique for FadeOut {
+    fn name(&self) -> &'static str {
+        "FadeOut"
+    }
+
+    fn duration(&self) -> f64 {
+        self.duration
+    }
+
+    fn apply(&self, progress: f64) -> TechniqueOutput {
+        TechniqueOutput {
+            opacity: 1.0 - progress.clamp(0.0, 1.0),
+            ..TechniqueOutput::default()
+        }
+    }
+
+    fn is_exit(&self) -> bool {
+        true
+    }
+}
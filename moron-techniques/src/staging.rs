@@ -109,4 +109,8 @@ impl<T: Technique> Technique for Stagger<T> {
             .map(|i| self.apply_item_for_count(i, count, progress))
             .collect()
     }
+
+    fn is_exit(&self) -> bool {
+        self.inner.is_exit()
+    }
 }
@@ -122,6 +122,15 @@ pub trait Technique {
     fn apply_items(&self, count: usize, progress: f64) -> Vec<TechniqueOutput> {
         (0..count).map(|_| self.apply(progress)).collect()
     }
+
+    /// Whether this technique takes an element off screen, running from the
+    /// resting state at 0.0 to gone at 1.0 (e.g., `FadeOut`).
+    ///
+    /// Default: `false`. Techniques that bring an element in are played in
+    /// reverse when used to remove one.
+    fn is_exit(&self) -> bool {
+        false
+    }
 }
 
 /// Extension trait providing combinators on any [`Technique`].
@@ -163,4 +172,8 @@ impl<T: Technique> Technique for WithEase<T> {
         let eased = ease(self.ease, progress);
         self.inner.apply_items(count, eased)
     }
+
+    fn is_exit(&self) -> bool {
+        self.inner.is_exit()
+    }
 }
@@ -1 +1,217 @@
-//! Transition techniques: `CrossFade`, Morph, `CutTo`, `IrisWipe`, etc.
+//! Transition techniques: `Crossfade`, `Push`, `Iris`, `MorphCut`.
+//!
+//! A transition animates two groups of elements at once: the outgoing set
+//! leaving the screen and the incoming set replacing it.
+
+use crate::technique::TechniqueOutput;
+
+// ---------------------------------------------------------------------------
+// Transition trait
+// ---------------------------------------------------------------------------
+
+/// A scene change between an outgoing and an incoming set of elements.
+///
+/// Both methods take the same progress value (0.0 = start, 1.0 = end).
+/// At 0.0 the outgoing set is untouched; at 1.0 the incoming set is at rest.
+pub trait Transition {
+    /// Human-readable name of this transition.
+    fn name(&self) -> &str;
+
+    /// Duration of the transition in seconds.
+    fn duration(&self) -> f64;
+
+    /// Visual output for the elements leaving the screen.
+    fn outgoing(&self, progress: f64) -> TechniqueOutput;
+
+    /// Visual output for the elements entering the screen.
+    fn incoming(&self, progress: f64) -> TechniqueOutput;
+}
+
+// ---------------------------------------------------------------------------
+// Crossfade
+// ---------------------------------------------------------------------------
+
+/// Dissolves the outgoing set into the incoming set.
+#[derive(Debug, Clone)]
+pub struct Crossfade {
+    pub duration: f64,
+}
+
+impl Default for Crossfade {
+    fn default() -> Self {
+        Self { duration: 0.6 }
+    }
+}
+
+impl Transition for Crossfade {
+    fn name(&self) -> &'static str {
+        "Crossfade"
+    }
+
+    fn duration(&self) -> f64 {
+        self.duration
+    }
+
+    fn outgoing(&self, progress: f64) -> TechniqueOutput {
+        TechniqueOutput {
+            opacity: 1.0 - progress.clamp(0.0, 1.0),
+            ..TechniqueOutput::default()
+        }
+    }
+
+    fn incoming(&self, progress: f64) -> TechniqueOutput {
+        TechniqueOutput {
+            opacity: progress.clamp(0.0, 1.0),
+            ..TechniqueOutput::default()
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Push
+// ---------------------------------------------------------------------------
+
+/// Slides the incoming set in from the right, pushing the outgoing set off
+/// to the left.
+///
+/// `distance` is the horizontal travel in pixels, normally the frame width.
+/// Use a negative distance to push in the opposite direction.
+#[derive(Debug, Clone)]
+pub struct Push {
+    pub duration: f64,
+    pub distance: f64,
+}
+
+impl Default for Push {
+    fn default() -> Self {
+        Self {
+            duration: 0.7,
+            distance: 1920.0,
+        }
+    }
+}
+
+impl Transition for Push {
+    fn name(&self) -> &'static str {
+        "Push"
+    }
+
+    fn duration(&self) -> f64 {
+        self.duration
+    }
+
+    fn outgoing(&self, progress: f64) -> TechniqueOutput {
+        let p = progress.clamp(0.0, 1.0);
+        TechniqueOutput {
+            translate_x: -self.distance * p,
+            ..TechniqueOutput::default()
+        }
+    }
+
+    fn incoming(&self, progress: f64) -> TechniqueOutput {
+        let p = progress.clamp(0.0, 1.0);
+        TechniqueOutput {
+            translate_x: self.distance * (1.0 - p),
+            ..TechniqueOutput::default()
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Iris
+// ---------------------------------------------------------------------------
+
+/// Closes down on the outgoing set, then opens up on the incoming set.
+///
+/// The first half of the transition shrinks the outgoing set to nothing and
+/// the second half grows the incoming set from nothing, so the two never
+/// share the screen.
+#[derive(Debug, Clone)]
+pub struct Iris {
+    pub duration: f64,
+}
+
+impl Default for Iris {
+    fn default() -> Self {
+        Self { duration: 0.8 }
+    }
+}
+
+impl Transition for Iris {
+    fn name(&self) -> &'static str {
+        "Iris"
+    }
+
+    fn duration(&self) -> f64 {
+        self.duration
+    }
+
+    fn outgoing(&self, progress: f64) -> TechniqueOutput {
+        let closing = (1.0 - 2.0 * progress.clamp(0.0, 1.0)).max(0.0);
+        TechniqueOutput {
+            opacity: if closing > 0.0 { 1.0 } else { 0.0 },
+            scale: closing,
+            ..TechniqueOutput::default()
+        }
+    }
+
+    fn incoming(&self, progress: f64) -> TechniqueOutput {
+        let opening = (2.0 * progress.clamp(0.0, 1.0) - 1.0).max(0.0);
+        TechniqueOutput {
+            opacity: if opening > 0.0 { 1.0 } else { 0.0 },
+            scale: opening,
+            ..TechniqueOutput::default()
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// MorphCut
+// ---------------------------------------------------------------------------
+
+/// A near-instant cut softened by a brief dissolve and a slight zoom.
+///
+/// The outgoing set grows by `zoom` as it fades out; the incoming set
+/// settles from `1.0 - zoom` to full size as it fades in.
+#[derive(Debug, Clone)]
+pub struct MorphCut {
+    pub duration: f64,
+    pub zoom: f64,
+}
+
+impl Default for MorphCut {
+    fn default() -> Self {
+        Self {
+            duration: 0.2,
+            zoom: 0.05,
+        }
+    }
+}
+
+impl Transition for MorphCut {
+    fn name(&self) -> &'static str {
+        "MorphCut"
+    }
+
+    fn duration(&self) -> f64 {
+        self.duration
+    }
+
+    fn outgoing(&self, progress: f64) -> TechniqueOutput {
+        let p = progress.clamp(0.0, 1.0);
+        TechniqueOutput {
+            opacity: 1.0 - p,
+            scale: 1.0 + self.zoom * p,
+            ..TechniqueOutput::default()
+        }
+    }
+
+    fn incoming(&self, progress: f64) -> TechniqueOutput {
+        let p = progress.clamp(0.0, 1.0);
+        TechniqueOutput {
+            opacity: p,
+            scale: 1.0 - self.zoom * (1.0 - p),
+            ..TechniqueOutput::default()
+        }
+    }
+}
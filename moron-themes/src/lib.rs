@@ -6,7 +6,7 @@ pub mod defaults;
 pub mod theme;
 
 // Re-export all public types at crate root for convenience.
-pub use theme::{Theme, ThemeColors, ThemeShadows, ThemeSpacing, ThemeTiming, ThemeTypography};
+pub use theme::{mix_color, Theme, ThemeColors, ThemeShadows, ThemeSpacing, ThemeTiming, ThemeTypography};
 
 #[cfg(test)]
 mod tests {
@@ -160,4 +160,45 @@ mod tests {
         let deserialized: Theme = serde_json::from_str(&json).expect("deserialize from JSON");
         assert_eq!(theme, deserialized);
     }
+
+    #[test]
+    fn mix_color_hex_midpoint() {
+        assert_eq!(mix_color("#000000", "#ffffff", 0.5).as_deref(), Some("#808080"));
+        assert_eq!(mix_color("#0f172a", "#ffffff", 0.0).as_deref(), Some("#0f172a"));
+        assert_eq!(mix_color("#0f172a", "#ffffff", 1.0).as_deref(), Some("#ffffff"));
+        assert_eq!(mix_color("#000", "#fff", 1.0).as_deref(), Some("#ffffff"));
+    }
+
+    #[test]
+    fn mix_color_rgba() {
+        let mixed = mix_color("rgba(0, 0, 0, 0.2)", "rgba(100, 200, 50, 0.4)", 0.5);
+        assert_eq!(mixed.as_deref(), Some("rgba(50, 100, 25, 0.3)"));
+    }
+
+    #[test]
+    fn mix_color_rejects_unparseable() {
+        assert_eq!(mix_color("red", "#ffffff", 0.5), None);
+        assert_eq!(mix_color("#12345", "#ffffff", 0.5), None);
+    }
+
+    #[test]
+    fn blend_endpoints_match_themes() {
+        let dark = Theme::default();
+        let light = Theme::light();
+        assert_eq!(dark.blend(&light, 0.0), dark);
+        assert_eq!(dark.blend(&light, 1.0), light);
+    }
+
+    #[test]
+    fn blend_midpoint_interpolates_colors() {
+        let dark = Theme::default();
+        let light = Theme::light();
+        let mid = dark.blend(&light, 0.5);
+
+        assert_eq!(mid.name, "moron-light");
+        assert_ne!(mid.colors.bg_primary, dark.colors.bg_primary);
+        assert_ne!(mid.colors.bg_primary, light.colors.bg_primary);
+        // fg_muted is identical in both themes.
+        assert_eq!(mid.colors.fg_muted, "#64748b");
+    }
 }
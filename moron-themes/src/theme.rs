@@ -205,3 +205,105 @@ impl Theme {
         props
     }
 }
+
+// ── Interpolation ───────────────────────────────────────────────────────────
+
+impl Theme {
+    /// Blend from `self` towards `to` at `t` (0.0 = `self`, 1.0 = `to`).
+    ///
+    /// Color tokens are interpolated channel by channel when both values are
+    /// `#rgb`, `#rrggbb`, or `rgb()`/`rgba()` colors. Every other token, and
+    /// any color that can't be parsed, switches over at the halfway point.
+    pub fn blend(&self, to: &Theme, t: f64) -> Theme {
+        if t <= 0.0 {
+            return self.clone();
+        }
+        if t >= 1.0 {
+            return to.clone();
+        }
+        let mut out = if t < 0.5 { self.clone() } else { to.clone() };
+
+        let (a, b) = (&self.colors, &to.colors);
+        let c = &mut out.colors;
+        for (dst, from, into) in [
+            (&mut c.bg_primary, &a.bg_primary, &b.bg_primary),
+            (&mut c.bg_secondary, &a.bg_secondary, &b.bg_secondary),
+            (&mut c.bg_tertiary, &a.bg_tertiary, &b.bg_tertiary),
+            (&mut c.fg_primary, &a.fg_primary, &b.fg_primary),
+            (&mut c.fg_secondary, &a.fg_secondary, &b.fg_secondary),
+            (&mut c.fg_muted, &a.fg_muted, &b.fg_muted),
+            (&mut c.accent, &a.accent, &b.accent),
+            (&mut c.accent_hover, &a.accent_hover, &b.accent_hover),
+            (&mut c.accent_subtle, &a.accent_subtle, &b.accent_subtle),
+            (&mut c.success, &a.success, &b.success),
+            (&mut c.warning, &a.warning, &b.warning),
+            (&mut c.error, &a.error, &b.error),
+        ] {
+            if let Some(mixed) = mix_color(from, into, t) {
+                *dst = mixed;
+            }
+        }
+
+        out
+    }
+}
+
+/// Interpolate between two CSS colors, or `None` if either can't be parsed.
+///
+/// The result is `#rrggbb` when fully opaque and `rgba(...)` otherwise.
+pub fn mix_color(from: &str, to: &str, t: f64) -> Option<String> {
+    let a = parse_color(from)?;
+    let b = parse_color(to)?;
+    let lerp = |x: f64, y: f64| x + (y - x) * t;
+
+    let [r, g, bl, alpha] = [0, 1, 2, 3].map(|i| lerp(a[i], b[i]));
+    let (r, g, bl) = (r.round() as u8, g.round() as u8, bl.round() as u8);
+
+    if (alpha - 1.0).abs() < 1e-6 {
+        Some(format!("#{r:02x}{g:02x}{bl:02x}"))
+    } else {
+        let alpha = (alpha * 1000.0).round() / 1000.0;
+        Some(format!("rgba({r}, {g}, {bl}, {alpha})"))
+    }
+}
+
+/// Parse a hex or `rgb()`/`rgba()` color into `[r, g, b, a]` (channels 0-255,
+/// alpha 0-1).
+fn parse_color(value: &str) -> Option<[f64; 4]> {
+    let value = value.trim();
+
+    if let Some(hex) = value.strip_prefix('#') {
+        let channel = |s: &str| u8::from_str_radix(s, 16).ok().map(f64::from);
+        return match hex.len() {
+            3 => {
+                let mut out = [0.0, 0.0, 0.0, 1.0];
+                for (i, ch) in hex.chars().enumerate() {
+                    out[i] = channel(&ch.to_string().repeat(2))?;
+                }
+                Some(out)
+            }
+            6 => Some([
+                channel(hex.get(0..2)?)?,
+                channel(hex.get(2..4)?)?,
+                channel(hex.get(4..6)?)?,
+                1.0,
+            ]),
+            _ => None,
+        };
+    }
+
+    let inner = value
+        .strip_prefix("rgba(")
+        .or_else(|| value.strip_prefix("rgb("))?
+        .strip_suffix(')')?;
+    let parts: Vec<f64> = inner
+        .split(',')
+        .map(|p| p.trim().parse::<f64>().ok())
+        .collect::<Option<_>>()?;
+
+    match parts.as_slice() {
+        [r, g, b] => Some([*r, *g, *b, 1.0]),
+        [r, g, b, a] => Some([*r, *g, *b, *a]),
+        _ => None,
+    }
+}
@@ -1 +1,213 @@
 //! Audio-text alignment: word-level timestamp generation for lip sync and captions.
+//!
+//! Backends that know when each word is spoken report it through
+//! [`VoiceBackend::synthesize_with_timings`](crate::VoiceBackend::synthesize_with_timings).
+//! For everything else, [`align`] estimates timings offline from the
+//! synthesized audio: it finds where speech is (by signal energy), skips
+//! pauses, and spreads the words over the speech in proportion to their
+//! length. [`estimate`] does the same over a plain duration when there is no
+//! audio at all.
+
+use std::ops::Range;
+
+use crate::audio::AudioClip;
+
+/// Length of the analysis window used to measure signal energy, in seconds.
+const WINDOW: f64 = 0.01;
+
+/// Quiet stretches shorter than this (seconds) count as part of the speech.
+const MIN_PAUSE: f64 = 0.15;
+
+/// Windows quieter than this fraction of the loudest window are silence.
+const SILENCE_RATIO: f32 = 0.05;
+
+/// When a single word is spoken, in seconds from the start of its narration.
+#[derive(Debug, Clone, PartialEq)]
+pub struct WordTiming {
+    /// The word as written in the narration text, punctuation included.
+    pub word: String,
+    /// Start time in seconds.
+    pub start: f64,
+    /// End time in seconds.
+    pub end: f64,
+}
+
+/// Estimate word timings for `text` spoken in `clip`.
+///
+/// Leading and trailing silence and pauses of at least 150 ms are left
+/// unassigned; the words are spread over the remaining speech. A silent
+/// clip falls back to [`estimate`] over its whole duration.
+pub fn align(text: &str, clip: &AudioClip) -> Vec<WordTiming> {
+    let spans = speech_spans(clip);
+    if spans.is_empty() {
+        return estimate(text, clip.duration());
+    }
+    distribute(text, &spans)
+}
+
+/// Spread the words of `text` over `duration` seconds in proportion to
+/// their length.
+pub fn estimate(text: &str, duration: f64) -> Vec<WordTiming> {
+    distribute(text, &[0.0..duration.max(0.0)])
+}
+
+/// Assign each word a share of the total span length proportional to its
+/// weight, laying the shares end to end across `spans`.
+fn distribute(text: &str, spans: &[Range<f64>]) -> Vec<WordTiming> {
+    let words: Vec<&str> = text.split_whitespace().collect();
+    let weights: Vec<f64> = words.iter().map(|w| word_weight(w)).collect();
+    let total_weight: f64 = weights.iter().sum();
+    let speech: f64 = spans.iter().map(|s| s.end - s.start).sum();
+
+    let mut spoken = 0.0;
+    words
+        .iter()
+        .zip(&weights)
+        .map(|(word, weight)| {
+            let from = spoken / total_weight * speech;
+            spoken += weight;
+            let to = spoken / total_weight * speech;
+            WordTiming {
+                word: word.to_string(),
+                start: locate(spans, from, true),
+                end: locate(spans, to, false),
+            }
+        })
+        .collect()
+}
+
+/// Relative speaking time of a word: its letters and digits, plus one for
+/// the gap after it.
+fn word_weight(word: &str) -> f64 {
+    (word.chars().filter(|c| c.is_alphanumeric()).count() + 1) as f64
+}
+
+/// Map `offset` seconds of speech onto clip time across `spans`.
+///
+/// An offset that lands exactly on a pause maps to the start of the next
+/// span when `after_pause` is set (a word starting), or the end of the
+/// previous one otherwise (a word ending).
+fn locate(spans: &[Range<f64>], offset: f64, after_pause: bool) -> f64 {
+    let mut covered = 0.0;
+    for (i, span) in spans.iter().enumerate() {
+        let len = span.end - span.start;
+        let last = i + 1 == spans.len();
+        if offset < covered + len || (offset <= covered + len && (!after_pause || last)) {
+            return span.start + (offset - covered).max(0.0);
+        }
+        covered += len;
+    }
+    spans.last().map_or(0.0, |s| s.end)
+}
+
+/// Time ranges of `clip` that contain speech, with short gaps bridged.
+fn speech_spans(clip: &AudioClip) -> Vec<Range<f64>> {
+    let mono = clip.to_mono();
+    let window = ((f64::from(mono.sample_rate) * WINDOW) as usize).max(1);
+    let levels: Vec<f32> = mono
+        .data
+        .chunks(window)
+        .map(|w| (w.iter().map(|s| s * s).sum::<f32>() / w.len() as f32).sqrt())
+        .collect();
+
+    let peak = levels.iter().copied().fold(0.0, f32::max);
+    if peak <= f32::EPSILON {
+        return Vec::new();
+    }
+    let threshold = peak * SILENCE_RATIO;
+    let to_seconds = |index: usize| (index * window).min(mono.data.len()) as f64 / f64::from(mono.sample_rate);
+
+    let mut spans: Vec<Range<f64>> = Vec::new();
+    let mut start = None;
+    for (i, &level) in levels.iter().chain(std::iter::once(&0.0)).enumerate() {
+        match (level > threshold, start) {
+            (true, None) => start = Some(i),
+            (false, Some(from)) => {
+                let span = to_seconds(from)..to_seconds(i);
+                match spans.last_mut() {
+                    Some(prev) if span.start - prev.end < MIN_PAUSE => prev.end = span.end,
+                    _ => spans.push(span),
+                }
+                start = None;
+            }
+            _ => {}
+        }
+    }
+    spans
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A 1 kHz clip that is loud during `loud` ranges and silent elsewhere.
+    fn clip_with_speech(duration: f64, loud: &[Range<f64>]) -> AudioClip {
+        let mut clip = AudioClip::silence(duration, 1000);
+        for (i, sample) in clip.data.iter_mut().enumerate() {
+            let t = i as f64 / 1000.0;
+            if loud.iter().any(|r| r.contains(&t)) {
+                *sample = if i % 2 == 0 { 0.5 } else { -0.5 };
+            }
+        }
+        clip
+    }
+
+    fn assert_close(actual: f64, expected: f64) {
+        assert!((actual - expected).abs() < 1e-9, "{actual} vs {expected}");
+    }
+
+    #[test]
+    fn estimate_is_proportional_to_word_length() {
+        // Weights: "a" = 2, "bbb" = 4, "cc." = 3 (punctuation is not spoken).
+        let words = estimate("a bbb cc.", 9.0);
+        assert_eq!(words.len(), 3);
+        assert_eq!(words[2].word, "cc.");
+        assert_close(words[0].start, 0.0);
+        assert_close(words[0].end, 2.0);
+        assert_close(words[1].start, 2.0);
+        assert_close(words[1].end, 6.0);
+        assert_close(words[2].end, 9.0);
+    }
+
+    #[test]
+    fn empty_text_has_no_words() {
+        assert!(estimate("   ", 3.0).is_empty());
+        assert!(align("", &AudioClip::silence(1.0, 1000)).is_empty());
+    }
+
+    #[test]
+    fn silent_clip_falls_back_to_estimate() {
+        let clip = AudioClip::silence(2.0, 1000);
+        assert_eq!(align("one two", &clip), estimate("one two", 2.0));
+    }
+
+    #[test]
+    fn align_skips_leading_and_trailing_silence() {
+        let clip = clip_with_speech(3.0, &[0.5..2.5]);
+        let words = align("abc def", &clip);
+        assert_close(words[0].start, 0.5);
+        assert_close(words[0].end, 1.5);
+        assert_close(words[1].start, 1.5);
+        assert_close(words[1].end, 2.5);
+    }
+
+    #[test]
+    fn align_words_resume_after_pause() {
+        // Two equal words, one on each side of a half-second pause.
+        let clip = clip_with_speech(2.5, &[0.0..1.0, 1.5..2.5]);
+        let words = align("abc def", &clip);
+        assert_close(words[0].end, 1.0);
+        assert_close(words[1].start, 1.5);
+        assert_close(words[1].end, 2.5);
+    }
+
+    #[test]
+    fn short_gaps_are_bridged() {
+        let clip = clip_with_speech(2.0, &[0.0..0.9, 1.0..2.0]);
+        assert_eq!(speech_spans(&clip).len(), 1);
+    }
+}
@@ -1,5 +1,7 @@
 //! Audio utilities: format conversion, normalization, and mixing.
 
+use std::path::{Path, PathBuf};
+
 /// Default sample rate for video production (48 kHz, broadcast standard).
 pub const DEFAULT_SAMPLE_RATE: u32 = 48000;
 
@@ -13,6 +15,19 @@ pub enum AudioError {
     /// Attempted to combine clips with different channel counts.
     #[error("channel count mismatch: expected {expected}, got {got}")]
     ChannelCountMismatch { expected: u16, got: u16 },
+
+    /// WAV data is malformed or uses an encoding that can't be decoded.
+    #[error("invalid WAV data: {0}")]
+    InvalidWav(String),
+
+    /// An audio file could not be read.
+    #[error("failed to read {path}: {source}")]
+    Io {
+        /// The file that was read.
+        path: PathBuf,
+        /// The underlying I/O error.
+        source: std::io::Error,
+    },
 }
 
 /// Raw audio clip produced by a TTS backend.
@@ -36,7 +51,148 @@ fn f32_to_i16(sample: f32) -> i16 {
     (clamped * 32767.0) as i16
 }
 
+/// Convert a 16-bit signed integer sample to f32 in [-1.0, 1.0).
+fn i16_to_f32(sample: i16) -> f32 {
+    f32::from(sample) / 32768.0
+}
+
+/// Zero crossings on each side of the resampling kernel. Higher values give
+/// a sharper filter at the cost of speed.
+const SINC_ZERO_CROSSINGS: f64 = 16.0;
+
+/// Blackman-windowed sinc, with `x` in zero crossings from the center.
+fn sinc_kernel(x: f64) -> f64 {
+    use std::f64::consts::PI;
+
+    if x.abs() >= SINC_ZERO_CROSSINGS {
+        return 0.0;
+    }
+    let sinc = if x == 0.0 { 1.0 } else { (PI * x).sin() / (PI * x) };
+    let u = x / SINC_ZERO_CROSSINGS;
+    let window = 0.42 + 0.5 * (PI * u).cos() + 0.08 * (2.0 * PI * u).cos();
+    sinc * window
+}
+
+/// WAV `fmt ` format tag for integer PCM.
+const WAVE_FORMAT_PCM: u16 = 1;
+
+/// WAV `fmt ` format tag for IEEE floating-point samples.
+const WAVE_FORMAT_IEEE_FLOAT: u16 = 3;
+
+/// WAV `fmt ` format tag whose real format is in the sub-format GUID.
+const WAVE_FORMAT_EXTENSIBLE: u16 = 0xFFFE;
+
+/// A RIFF chunk: its four-character id and its body.
+pub(crate) type WavChunk<'a> = ([u8; 4], &'a [u8]);
+
+/// Split a RIFF/WAVE file into its chunks, in file order.
+///
+/// A chunk whose declared size runs past the end of the data is truncated
+/// to what is available, which is how streamed WAV output often ends.
+pub(crate) fn wav_chunks(bytes: &[u8]) -> Result<Vec<WavChunk<'_>>, AudioError> {
+    if bytes.get(0..4) != Some(b"RIFF") || bytes.get(8..12) != Some(b"WAVE") {
+        return Err(AudioError::InvalidWav("missing RIFF/WAVE header".to_string()));
+    }
+
+    let mut chunks = Vec::new();
+    let mut pos = 12;
+    while let Some(header) = bytes.get(pos..pos + 8) {
+        let id = [header[0], header[1], header[2], header[3]];
+        let size = u32::from_le_bytes([header[4], header[5], header[6], header[7]]) as usize;
+        let start = pos + 8;
+        let end = start.saturating_add(size).min(bytes.len());
+        chunks.push((id, &bytes[start..end]));
+        // Chunks are padded to an even length.
+        pos = start.saturating_add(size).saturating_add(size & 1);
+    }
+    Ok(chunks)
+}
+
 impl AudioClip {
+    /// Decode a WAV file into a clip.
+    ///
+    /// Supports 16-, 24- and 32-bit integer PCM and 32-bit float samples,
+    /// including `WAVE_FORMAT_EXTENSIBLE` headers. Multi-channel audio stays
+    /// interleaved in `data`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AudioError::InvalidWav`] if the data is not a well-formed
+    /// WAV file or uses an unsupported encoding.
+    pub fn from_wav_bytes(bytes: &[u8]) -> Result<Self, AudioError> {
+        let invalid = |reason: &str| AudioError::InvalidWav(reason.to_string());
+        let chunks = wav_chunks(bytes)?;
+
+        let fmt = chunks
+            .iter()
+            .find(|(id, _)| id == b"fmt ")
+            .map(|(_, body)| *body)
+            .ok_or_else(|| invalid("missing fmt chunk"))?;
+        if fmt.len() < 16 {
+            return Err(invalid("short fmt chunk"));
+        }
+        let mut format = u16::from_le_bytes([fmt[0], fmt[1]]);
+        let channels = u16::from_le_bytes([fmt[2], fmt[3]]);
+        let sample_rate = u32::from_le_bytes([fmt[4], fmt[5], fmt[6], fmt[7]]);
+        let bits = u16::from_le_bytes([fmt[14], fmt[15]]);
+        if format == WAVE_FORMAT_EXTENSIBLE {
+            // The real format is the first two bytes of the sub-format GUID.
+            let sub = fmt.get(24..26).ok_or_else(|| invalid("short extensible fmt chunk"))?;
+            format = u16::from_le_bytes([sub[0], sub[1]]);
+        }
+        if channels == 0 || sample_rate == 0 {
+            return Err(invalid("zero channels or sample rate"));
+        }
+
+        let decode: fn(&[u8]) -> f32 = match (format, bits) {
+            (WAVE_FORMAT_PCM, 16) => |b| i16_to_f32(i16::from_le_bytes([b[0], b[1]])),
+            (WAVE_FORMAT_PCM, 24) => {
+                |b| i32::from_le_bytes([0, b[0], b[1], b[2]]) as f32 / 2_147_483_648.0
+            }
+            (WAVE_FORMAT_PCM, 32) => {
+                |b| i32::from_le_bytes([b[0], b[1], b[2], b[3]]) as f32 / 2_147_483_648.0
+            }
+            (WAVE_FORMAT_IEEE_FLOAT, 32) => |b| f32::from_le_bytes([b[0], b[1], b[2], b[3]]),
+            _ => {
+                return Err(AudioError::InvalidWav(format!(
+                    "unsupported encoding (format {format}, {bits}-bit); \
+                     expected 16/24/32-bit PCM or 32-bit float"
+                )));
+            }
+        };
+
+        let pcm = chunks
+            .iter()
+            .find(|(id, _)| id == b"data")
+            .map(|(_, body)| *body)
+            .ok_or_else(|| invalid("missing data chunk"))?;
+
+        let data: Vec<f32> = pcm.chunks_exact(usize::from(bits / 8)).map(decode).collect();
+        let duration = data.len() as f64 / (sample_rate as f64 * channels as f64);
+
+        Ok(Self {
+            data,
+            duration,
+            sample_rate,
+            channels,
+        })
+    }
+
+    /// Read and decode a WAV file from disk.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AudioError::Io`] if the file can't be read, or
+    /// [`AudioError::InvalidWav`] as for [`from_wav_bytes`](Self::from_wav_bytes).
+    pub fn from_wav_file(path: impl AsRef<Path>) -> Result<Self, AudioError> {
+        let path = path.as_ref();
+        let bytes = std::fs::read(path).map_err(|source| AudioError::Io {
+            path: path.to_path_buf(),
+            source,
+        })?;
+        Self::from_wav_bytes(&bytes)
+    }
+
     /// Create a silent audio clip of the given duration and sample rate (mono).
     pub fn silence(duration: f64, sample_rate: u32) -> Self {
         let num_samples = (duration * sample_rate as f64) as usize;
@@ -100,6 +256,105 @@ impl AudioClip {
         Ok(result)
     }
 
+    /// Repeat the clip end to end until it is `duration` seconds long.
+    ///
+    /// The last repetition is cut short as needed. An empty clip stays empty.
+    pub fn looped(&self, duration: f64) -> AudioClip {
+        let channels = usize::from(self.channels.max(1));
+        let frames = (duration.max(0.0) * f64::from(self.sample_rate)) as usize;
+        let data: Vec<f32> = self.data.iter().copied().cycle().take(frames * channels).collect();
+        AudioClip {
+            duration: (data.len() / channels) as f64 / f64::from(self.sample_rate),
+            data,
+            sample_rate: self.sample_rate,
+            channels: self.channels,
+        }
+    }
+
+    /// Downmix to a single channel by averaging each frame's channels.
+    pub fn to_mono(&self) -> AudioClip {
+        if self.channels <= 1 {
+            return self.clone();
+        }
+        let channels = usize::from(self.channels);
+        let data = self
+            .data
+            .chunks_exact(channels)
+            .map(|frame| frame.iter().sum::<f32>() / channels as f32)
+            .collect();
+        AudioClip {
+            data,
+            duration: self.duration,
+            sample_rate: self.sample_rate,
+            channels: 1,
+        }
+    }
+
+    /// Convert to two channels.
+    ///
+    /// Mono is copied to both sides; clips with more than two channels are
+    /// downmixed to mono first.
+    pub fn to_stereo(&self) -> AudioClip {
+        if self.channels == 2 {
+            return self.clone();
+        }
+        let mono = self.to_mono();
+        let data = mono.data.iter().flat_map(|&s| [s, s]).collect();
+        AudioClip {
+            data,
+            channels: 2,
+            ..mono
+        }
+    }
+
+    /// Resample to `sample_rate` with a windowed-sinc (Blackman) filter.
+    ///
+    /// When downsampling, the filter cutoff drops to the new Nyquist
+    /// frequency so content above it is removed rather than aliased. Each
+    /// channel is filtered independently.
+    pub fn resample(&self, sample_rate: u32) -> AudioClip {
+        if sample_rate == self.sample_rate || self.data.is_empty() || self.sample_rate == 0 {
+            return AudioClip {
+                sample_rate,
+                ..self.clone()
+            };
+        }
+
+        let channels = usize::from(self.channels.max(1));
+        let frames = self.data.len() / channels;
+        let ratio = f64::from(sample_rate) / f64::from(self.sample_rate);
+        let out_frames = (frames as f64 * ratio).round() as usize;
+        // Fraction of the input band kept, and the kernel reach in input frames.
+        let cutoff = ratio.min(1.0);
+        let reach = SINC_ZERO_CROSSINGS / cutoff;
+
+        let mut data = Vec::with_capacity(out_frames * channels);
+        let mut weights = Vec::new();
+        for out in 0..out_frames {
+            let center = out as f64 / ratio;
+            let first = (center - reach).ceil().max(0.0) as usize;
+            let last = ((center + reach).floor() as usize).min(frames - 1);
+
+            weights.clear();
+            weights.extend((first..=last).map(|i| cutoff * sinc_kernel((center - i as f64) * cutoff)));
+
+            for channel in 0..channels {
+                let sum: f64 = (first..=last)
+                    .zip(&weights)
+                    .map(|(i, w)| f64::from(self.data[i * channels + channel]) * w)
+                    .sum();
+                data.push(sum as f32);
+            }
+        }
+
+        AudioClip {
+            data,
+            duration: out_frames as f64 / f64::from(sample_rate),
+            sample_rate,
+            channels: self.channels,
+        }
+    }
+
     /// Encode this clip as WAV bytes (16-bit signed PCM, RIFF/WAVE container).
     ///
     /// Returns a `Vec<u8>` containing a complete WAV file: 44-byte header
@@ -317,6 +572,276 @@ mod tests {
         assert_eq!(s3, -32767); // -1.0 * 32767
     }
 
+    // -- from_wav_bytes tests -----------------------------------------------
+
+    #[test]
+    fn test_from_wav_bytes_round_trip() {
+        let mut clip = AudioClip::silence(0.0, 22050);
+        clip.data = vec![0.0, 0.5, -0.5, 0.25];
+        clip.duration = 4.0 / 22050.0;
+
+        let decoded = AudioClip::from_wav_bytes(&clip.to_wav_bytes()).unwrap();
+        assert_eq!(decoded.sample_rate, 22050);
+        assert_eq!(decoded.channels, 1);
+        assert_eq!(decoded.data.len(), 4);
+        for (a, b) in decoded.data.iter().zip(&clip.data) {
+            assert!((a - b).abs() < 1e-4, "{a} vs {b}");
+        }
+        assert!((decoded.duration() - clip.duration()).abs() < 1e-12);
+    }
+
+    /// Build a WAV file by hand with the given format tag and sample bytes.
+    fn encode_wav(format: u16, bits: u16, channels: u16, rate: u32, payload: &[u8]) -> Vec<u8> {
+        let extensible = format == WAVE_FORMAT_EXTENSIBLE;
+        let fmt_len: u32 = if extensible { 40 } else { 16 };
+        let block_align = channels * bits / 8;
+
+        let mut wav = Vec::new();
+        wav.extend_from_slice(b"RIFF");
+        wav.extend_from_slice(&(4 + 8 + fmt_len + 8 + payload.len() as u32).to_le_bytes());
+        wav.extend_from_slice(b"WAVE");
+        wav.extend_from_slice(b"fmt ");
+        wav.extend_from_slice(&fmt_len.to_le_bytes());
+        wav.extend_from_slice(&format.to_le_bytes());
+        wav.extend_from_slice(&channels.to_le_bytes());
+        wav.extend_from_slice(&rate.to_le_bytes());
+        wav.extend_from_slice(&(rate * u32::from(block_align)).to_le_bytes());
+        wav.extend_from_slice(&block_align.to_le_bytes());
+        wav.extend_from_slice(&bits.to_le_bytes());
+        if extensible {
+            wav.extend_from_slice(&22u16.to_le_bytes()); // cbSize
+            wav.extend_from_slice(&bits.to_le_bytes()); // valid bits
+            wav.extend_from_slice(&3u32.to_le_bytes()); // channel mask
+            // KSDATAFORMAT_SUBTYPE_IEEE_FLOAT
+            wav.extend_from_slice(&WAVE_FORMAT_IEEE_FLOAT.to_le_bytes());
+            wav.extend_from_slice(&[
+                0x00, 0x00, 0x00, 0x00, 0x10, 0x00, 0x80, 0x00, 0x00, 0xAA, 0x00, 0x38, 0x9B,
+                0x71,
+            ]);
+        }
+        wav.extend_from_slice(b"data");
+        wav.extend_from_slice(&(payload.len() as u32).to_le_bytes());
+        wav.extend_from_slice(payload);
+        wav
+    }
+
+    const SAMPLES: [f32; 4] = [0.0, 0.5, -0.5, -1.0];
+
+    fn assert_samples(clip: &AudioClip, tolerance: f32) {
+        assert_eq!(clip.data.len(), SAMPLES.len());
+        for (a, b) in clip.data.iter().zip(&SAMPLES) {
+            assert!((a - b).abs() <= tolerance, "{a} vs {b}");
+        }
+    }
+
+    #[test]
+    fn test_from_wav_bytes_stereo_round_trip() {
+        let clip = AudioClip {
+            data: SAMPLES.to_vec(),
+            duration: 2.0 / 48000.0,
+            sample_rate: 48000,
+            channels: 2,
+        };
+        let decoded = AudioClip::from_wav_bytes(&clip.to_wav_bytes()).unwrap();
+        assert_eq!(decoded.channels, 2);
+        assert_eq!(decoded.sample_rate, 48000);
+        assert_samples(&decoded, 1e-4);
+        assert!((decoded.duration() - clip.duration()).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_from_wav_bytes_24_bit() {
+        let payload: Vec<u8> = SAMPLES
+            .iter()
+            .flat_map(|&s| {
+                let v = (s * 8_388_608.0).clamp(-8_388_608.0, 8_388_607.0) as i32;
+                v.to_le_bytes()[..3].to_vec()
+            })
+            .collect();
+        let clip =
+            AudioClip::from_wav_bytes(&encode_wav(WAVE_FORMAT_PCM, 24, 1, 44100, &payload))
+                .unwrap();
+        assert_eq!(clip.sample_rate, 44100);
+        assert_samples(&clip, 1e-6);
+    }
+
+    #[test]
+    fn test_from_wav_bytes_32_bit_int() {
+        let payload: Vec<u8> = SAMPLES
+            .iter()
+            .flat_map(|&s| ((f64::from(s) * 2_147_483_648.0).max(i32::MIN as f64) as i32).to_le_bytes())
+            .collect();
+        let clip =
+            AudioClip::from_wav_bytes(&encode_wav(WAVE_FORMAT_PCM, 32, 2, 48000, &payload))
+                .unwrap();
+        assert_eq!(clip.channels, 2);
+        assert_samples(&clip, 1e-6);
+        assert!((clip.duration() - 2.0 / 48000.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_from_wav_bytes_float_and_extensible() {
+        let payload: Vec<u8> = SAMPLES.iter().flat_map(|s| s.to_le_bytes()).collect();
+
+        let plain = encode_wav(WAVE_FORMAT_IEEE_FLOAT, 32, 1, 24000, &payload);
+        assert_eq!(AudioClip::from_wav_bytes(&plain).unwrap().data, SAMPLES);
+
+        let extensible = encode_wav(WAVE_FORMAT_EXTENSIBLE, 32, 2, 24000, &payload);
+        let clip = AudioClip::from_wav_bytes(&extensible).unwrap();
+        assert_eq!(clip.channels, 2);
+        assert_eq!(clip.data, SAMPLES);
+    }
+
+    #[test]
+    fn test_from_wav_bytes_rejects_unsupported_encoding() {
+        let wav = encode_wav(WAVE_FORMAT_IEEE_FLOAT, 64, 1, 8000, &[0; 16]);
+        let err = AudioClip::from_wav_bytes(&wav).unwrap_err();
+        assert!(err.to_string().contains("64-bit"), "got: {err}");
+    }
+
+    #[test]
+    fn test_from_wav_file() {
+        let dir = std::env::temp_dir().join("moron_test_from_wav_file");
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("tone.wav");
+        std::fs::write(&path, AudioClip::silence(0.25, 8000).to_wav_bytes()).unwrap();
+
+        let clip = AudioClip::from_wav_file(&path).unwrap();
+        assert_eq!(clip.data.len(), 2000);
+        assert!((clip.duration() - 0.25).abs() < 1e-12);
+
+        let err = AudioClip::from_wav_file(dir.join("missing.wav")).unwrap_err();
+        assert!(matches!(err, AudioError::Io { .. }));
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_from_wav_bytes_rejects_garbage() {
+        let err = AudioClip::from_wav_bytes(b"not a wav file").unwrap_err();
+        assert!(matches!(err, AudioError::InvalidWav(_)));
+    }
+
+    #[test]
+    fn test_wav_chunks_skips_unknown_chunks() {
+        let mut wav = AudioClip::silence(0.001, 8000).to_wav_bytes();
+        // Append an odd-sized LIST chunk with a pad byte.
+        wav.extend_from_slice(b"LIST");
+        wav.extend_from_slice(&3u32.to_le_bytes());
+        wav.extend_from_slice(&[1, 2, 3, 0]);
+
+        let ids: Vec<[u8; 4]> = wav_chunks(&wav).unwrap().iter().map(|(id, _)| *id).collect();
+        assert_eq!(ids, [*b"fmt ", *b"data", *b"LIST"]);
+    }
+
+    // -- Conversion tests ---------------------------------------------------
+
+    /// A mono sine tone of `freq` Hz, one second long.
+    fn tone(freq: f64, sample_rate: u32) -> AudioClip {
+        let data = (0..sample_rate)
+            .map(|i| (2.0 * std::f64::consts::PI * freq * f64::from(i) / f64::from(sample_rate)).sin() as f32)
+            .collect();
+        AudioClip {
+            data,
+            duration: 1.0,
+            sample_rate,
+            channels: 1,
+        }
+    }
+
+    /// RMS level of the middle half of a clip, away from edge effects.
+    fn middle_rms(clip: &AudioClip) -> f32 {
+        let n = clip.data.len();
+        let middle = &clip.data[n / 4..3 * n / 4];
+        (middle.iter().map(|s| s * s).sum::<f32>() / middle.len() as f32).sqrt()
+    }
+
+    #[test]
+    fn test_looped_repeats_and_truncates() {
+        let clip = AudioClip {
+            data: vec![0.0, 0.1, 1.0, 1.1],
+            duration: 2.0 / 1000.0,
+            sample_rate: 1000,
+            channels: 2,
+        };
+        let looped = clip.looped(0.005);
+        assert_eq!(
+            looped.data,
+            vec![0.0, 0.1, 1.0, 1.1, 0.0, 0.1, 1.0, 1.1, 0.0, 0.1]
+        );
+        assert!((looped.duration() - 0.005).abs() < 1e-12);
+        assert!(AudioClip::silence(0.0, 1000).looped(1.0).data.is_empty());
+    }
+
+    #[test]
+    fn test_to_mono_averages_channels() {
+        let stereo = AudioClip {
+            data: vec![1.0, 0.0, -0.5, -0.5],
+            duration: 2.0 / 8000.0,
+            sample_rate: 8000,
+            channels: 2,
+        };
+        let mono = stereo.to_mono();
+        assert_eq!(mono.channels, 1);
+        assert_eq!(mono.data, vec![0.5, -0.5]);
+        assert!((mono.duration() - stereo.duration()).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_to_stereo_duplicates_mono() {
+        let mut mono = AudioClip::silence(0.0, 8000);
+        mono.data = vec![0.25, -1.0];
+        mono.duration = 2.0 / 8000.0;
+
+        let stereo = mono.to_stereo();
+        assert_eq!(stereo.channels, 2);
+        assert_eq!(stereo.data, vec![0.25, 0.25, -1.0, -1.0]);
+        assert_eq!(stereo.to_mono().data, mono.data);
+    }
+
+    #[test]
+    fn test_resample_same_rate_is_identity() {
+        let clip = tone(440.0, 8000);
+        let same = clip.resample(8000);
+        assert_eq!(same.data, clip.data);
+    }
+
+    #[test]
+    fn test_resample_upsample_keeps_tone() {
+        let clip = tone(1000.0, 24000).resample(48000);
+        assert_eq!(clip.sample_rate, 48000);
+        assert_eq!(clip.data.len(), 48000);
+        assert!((clip.duration() - 1.0).abs() < 1e-12);
+        // A full-scale sine has an RMS of 1/sqrt(2).
+        assert!((middle_rms(&clip) - std::f32::consts::FRAC_1_SQRT_2).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_resample_downsample_removes_content_above_nyquist() {
+        let kept = tone(1000.0, 48000).resample(16000);
+        assert_eq!(kept.data.len(), 16000);
+        assert!((middle_rms(&kept) - std::f32::consts::FRAC_1_SQRT_2).abs() < 0.01);
+
+        // 12 kHz is above the 8 kHz Nyquist limit of the target rate.
+        let removed = tone(12000.0, 48000).resample(16000);
+        assert!(middle_rms(&removed) < 0.01, "rms = {}", middle_rms(&removed));
+    }
+
+    #[test]
+    fn test_resample_stereo_keeps_channels_apart() {
+        let left = tone(500.0, 22050);
+        let stereo = AudioClip {
+            data: left.data.iter().flat_map(|&s| [s, 0.0]).collect(),
+            channels: 2,
+            ..left
+        };
+        let resampled = stereo.resample(44100);
+        assert_eq!(resampled.channels, 2);
+        assert_eq!(resampled.data.len(), 2 * 44100);
+        assert!(resampled.data.iter().skip(1).step_by(2).all(|&s| s.abs() < 1e-6));
+        assert!(resampled.to_mono().data.iter().any(|&s| s.abs() > 0.4));
+    }
+
     // -- DEFAULT_SAMPLE_RATE test -------------------------------------------
 
     #[test]
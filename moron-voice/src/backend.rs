@@ -2,19 +2,54 @@
 
 use std::path::PathBuf;
 
+use crate::alignment::WordTiming;
 use crate::audio::AudioClip;
+use crate::kokoro::KokoroVoice;
 
 /// Common trait for all TTS synthesis backends.
 pub trait VoiceBackend {
     /// Synthesize the given text into an audio clip.
     fn synthesize(&self, text: &str) -> Result<AudioClip, anyhow::Error>;
 
+    /// Synthesize the given text, along with when each word is spoken if the
+    /// engine reports it.
+    ///
+    /// The default calls [`synthesize`](Self::synthesize) and reports no
+    /// timings; callers can fall back to [`align`](crate::alignment::align).
+    fn synthesize_with_timings(
+        &self,
+        text: &str,
+    ) -> Result<(AudioClip, Option<Vec<WordTiming>>), anyhow::Error> {
+        Ok((self.synthesize(text)?, None))
+    }
+
+    /// Whether synthesizing `text` would be served from a cache, or `None`
+    /// if the backend has no cache (the default).
+    ///
+    /// Lets callers report cache hits and misses; see
+    /// [`CachingBackend`](crate::CachingBackend).
+    fn is_cached(&self, _text: &str) -> Option<bool> {
+        None
+    }
+
+    /// Number of recorded sections, for backends that play back a recording
+    /// instead of synthesizing the text, or `None` for a synthesizer (the
+    /// default).
+    ///
+    /// Such a backend ignores the text and hands out its sections in call
+    /// order, so callers must request exactly this many clips, one per
+    /// narration in timeline order, and must not cache them
+    /// ([`CachingBackend`](crate::CachingBackend) passes them through).
+    fn recorded_sections(&self) -> Option<usize> {
+        None
+    }
+
     /// Human-readable name of this backend (e.g. "kokoro", "piper").
     fn name(&self) -> &str;
 }
 
 /// Which backend implementation to use for voice synthesis.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum VoiceBackendType {
     /// Kokoro — primary local TTS engine.
     Kokoro,
@@ -27,7 +62,7 @@ pub enum VoiceBackendType {
 }
 
 /// Configuration for a voice: which backend to use and synthesis parameters.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct Voice {
     /// The backend type to use for synthesis.
     pub backend_type: VoiceBackendType,
@@ -35,6 +70,11 @@ pub struct Voice {
     pub speed: f64,
     /// Pitch shift multiplier (1.0 = normal).
     pub pitch: f64,
+    /// Speaker used when `backend_type` is [`VoiceBackendType::Kokoro`].
+    pub kokoro_voice: KokoroVoice,
+    /// Speaker id used when `backend_type` is [`VoiceBackendType::Piper`]
+    /// and the model has several speakers.
+    pub piper_speaker: Option<u32>,
 }
 
 impl Voice {
@@ -44,6 +84,8 @@ impl Voice {
             backend_type: VoiceBackendType::Kokoro,
             speed: 1.0,
             pitch: 1.0,
+            kokoro_voice: KokoroVoice::default(),
+            piper_speaker: None,
         }
     }
 
@@ -53,6 +95,8 @@ impl Voice {
             backend_type: VoiceBackendType::Piper,
             speed: 1.0,
             pitch: 1.0,
+            kokoro_voice: KokoroVoice::default(),
+            piper_speaker: None,
         }
     }
 
@@ -62,8 +106,28 @@ impl Voice {
             backend_type: VoiceBackendType::PreRecorded(path.into()),
             speed: 1.0,
             pitch: 1.0,
+            kokoro_voice: KokoroVoice::default(),
+            piper_speaker: None,
         }
     }
+
+    /// Set the speech speed multiplier.
+    pub fn with_speed(mut self, speed: f64) -> Self {
+        self.speed = speed;
+        self
+    }
+
+    /// Set the Kokoro speaker.
+    pub fn with_kokoro_voice(mut self, voice: KokoroVoice) -> Self {
+        self.kokoro_voice = voice;
+        self
+    }
+
+    /// Set the Piper speaker id.
+    pub fn with_piper_speaker(mut self, speaker: u32) -> Self {
+        self.piper_speaker = Some(speaker);
+        self
+    }
 }
 
 #[cfg(test)]
@@ -96,4 +160,14 @@ mod tests {
             other => panic!("expected PreRecorded, got {:?}", other),
         }
     }
+
+    #[test]
+    fn builder_methods() {
+        let v = Voice::kokoro()
+            .with_speed(1.25)
+            .with_kokoro_voice(KokoroVoice::BmLewis);
+        assert!((v.speed - 1.25).abs() < f64::EPSILON);
+        assert_eq!(v.kokoro_voice, KokoroVoice::BmLewis);
+        assert_ne!(v, Voice::kokoro());
+    }
 }
@@ -0,0 +1,332 @@
+//! TTS cache: reuse synthesized narration across builds.
+//!
+//! [`CachingBackend`] wraps any other backend and stores each clip it
+//! synthesizes as a WAV file named after a hash of everything that shapes
+//! the audio: the text, the backend name, the voice settings and the model
+//! file. A later request with the same inputs reads the file back instead of
+//! running the engine again. Changing any input (or replacing the model file)
+//! changes the key, so stale entries are simply never read.
+//!
+//! Recordings (backends that report
+//! [`recorded_sections`](VoiceBackend::recorded_sections), like
+//! [`PreRecordedBackend`](crate::PreRecordedBackend)) are not cached: they
+//! ignore the text and hand out sections in call order, so a cached entry
+//! would be keyed by the wrong thing. Wrapping one passes every call
+//! straight through.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use moron_voice::{create_backend, BackendConfig, CachingBackend, Voice, VoiceBackend};
+//!
+//! let config = BackendConfig::from_env();
+//! let voice = Voice::kokoro();
+//! let backend = create_backend(&voice, &config).unwrap();
+//! let cached = CachingBackend::new(backend, ".moron/tts-cache")
+//!     .with_voice(&voice)
+//!     .with_model(config.kokoro_model_path.unwrap());
+//!
+//! let clip = cached.synthesize("Hello, world!").unwrap(); // synthesized
+//! let again = cached.synthesize("Hello, world!").unwrap(); // read from disk
+//! ```
+
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::UNIX_EPOCH;
+
+use crate::alignment::WordTiming;
+use crate::audio::AudioClip;
+use crate::backend::{Voice, VoiceBackend};
+
+/// FNV-1a 64-bit offset basis.
+const FNV_OFFSET: u64 = 0xcbf2_9ce4_8422_2325;
+
+/// FNV-1a 64-bit prime.
+const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+/// A backend that serves repeated requests from WAV files on disk.
+pub struct CachingBackend {
+    /// The backend that synthesizes on a cache miss.
+    inner: Arc<dyn VoiceBackend + Send + Sync>,
+    /// Directory holding the cached WAV files.
+    dir: PathBuf,
+    /// Everything besides the text that goes into the cache key, one
+    /// `name=value` entry per setting.
+    identity: Vec<String>,
+}
+
+impl CachingBackend {
+    /// Cache `inner`'s output under `dir`.
+    ///
+    /// The key covers the text and the backend name. Add the voice and the
+    /// model with [`with_voice`](Self::with_voice) and
+    /// [`with_model`](Self::with_model) so that changing either invalidates
+    /// the cache. A recording is never cached (see the module docs).
+    pub fn new(inner: Arc<dyn VoiceBackend + Send + Sync>, dir: impl Into<PathBuf>) -> Self {
+        let identity = vec![format!("backend={}", inner.name())];
+        Self {
+            inner,
+            dir: dir.into(),
+            identity,
+        }
+    }
+
+    /// Include the voice's speaker and speed (and pitch) in the cache key.
+    pub fn with_voice(mut self, voice: &Voice) -> Self {
+        self.identity.extend([
+            format!("kokoro_voice={:?}", voice.kokoro_voice),
+            format!("piper_speaker={:?}", voice.piper_speaker),
+            format!("speed={}", voice.speed),
+            format!("pitch={}", voice.pitch),
+        ]);
+        self
+    }
+
+    /// Include a model file in the cache key.
+    ///
+    /// Besides the path, the file's size and modification time are used, so
+    /// replacing the model in place invalidates the cache too.
+    pub fn with_model(mut self, path: impl AsRef<Path>) -> Self {
+        let path = path.as_ref();
+        let (len, modified) = std::fs::metadata(path)
+            .map(|meta| {
+                let modified = meta
+                    .modified()
+                    .ok()
+                    .and_then(|t| t.duration_since(UNIX_EPOCH).ok())
+                    .map_or(0, |d| d.as_nanos());
+                (meta.len(), modified)
+            })
+            .unwrap_or_default();
+        self.identity
+            .push(format!("model={}:{len}:{modified}", path.display()));
+        self
+    }
+
+    /// The cache key for `text`: 16 hex digits.
+    pub fn key(&self, text: &str) -> String {
+        let mut hash = FNV_OFFSET;
+        for part in self.identity.iter().map(String::as_str).chain([text]) {
+            // A separator after each part keeps ("ab", "c") and ("a", "bc") apart.
+            for &byte in part.as_bytes().iter().chain(&[0]) {
+                hash ^= u64::from(byte);
+                hash = hash.wrapping_mul(FNV_PRIME);
+            }
+        }
+        format!("{hash:016x}")
+    }
+
+    /// Where the clip for `text` is (or would be) cached.
+    pub fn path_for(&self, text: &str) -> PathBuf {
+        self.dir.join(format!("{}.wav", self.key(text)))
+    }
+
+    /// Read the cached clip for `text`. A missing or unreadable entry is a
+    /// miss.
+    fn load(&self, text: &str) -> Option<AudioClip> {
+        AudioClip::from_wav_file(self.path_for(text)).ok()
+    }
+
+    /// Whether the wrapped backend plays back a recording, which must not
+    /// be cached.
+    fn is_recording(&self) -> bool {
+        self.inner.recorded_sections().is_some()
+    }
+
+    /// Store `clip` for `text`. Best-effort: a cache that can't be written
+    /// only costs a re-synthesis next time.
+    fn store(&self, text: &str, clip: &AudioClip) {
+        let path = self.path_for(text);
+        // Write to a temporary name first so a crash never leaves a
+        // truncated entry behind.
+        let partial = path.with_extension("wav.partial");
+        let _ = std::fs::create_dir_all(&self.dir)
+            .and_then(|()| std::fs::write(&partial, clip.to_wav_bytes()))
+            .and_then(|()| std::fs::rename(&partial, &path));
+    }
+}
+
+impl VoiceBackend for CachingBackend {
+    fn synthesize(&self, text: &str) -> Result<AudioClip, anyhow::Error> {
+        Ok(self.synthesize_with_timings(text)?.0)
+    }
+
+    /// On a hit, no word timings are reported (callers align the audio
+    /// instead); on a miss, the wrapped backend's timings are passed through.
+    fn synthesize_with_timings(
+        &self,
+        text: &str,
+    ) -> Result<(AudioClip, Option<Vec<WordTiming>>), anyhow::Error> {
+        if self.is_recording() {
+            return self.inner.synthesize_with_timings(text);
+        }
+        if let Some(clip) = self.load(text) {
+            return Ok((clip, None));
+        }
+        let (clip, timings) = self.inner.synthesize_with_timings(text)?;
+        self.store(text, &clip);
+        Ok((clip, timings))
+    }
+
+    fn is_cached(&self, text: &str) -> Option<bool> {
+        if self.is_recording() {
+            return self.inner.is_cached(text);
+        }
+        Some(self.path_for(text).is_file())
+    }
+
+    fn recorded_sections(&self) -> Option<usize> {
+        self.inner.recorded_sections()
+    }
+
+    fn name(&self) -> &str {
+        self.inner.name()
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    /// A backend that counts calls and returns one 1 kHz sample per char.
+    struct CountingBackend {
+        calls: AtomicUsize,
+    }
+
+    impl VoiceBackend for CountingBackend {
+        fn synthesize(&self, text: &str) -> Result<AudioClip, anyhow::Error> {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            let data = vec![0.25; text.len()];
+            Ok(AudioClip {
+                duration: data.len() as f64 / 1000.0,
+                data,
+                sample_rate: 1000,
+                channels: 1,
+            })
+        }
+
+        fn name(&self) -> &str {
+            "counting"
+        }
+    }
+
+    fn counting() -> Arc<CountingBackend> {
+        Arc::new(CountingBackend {
+            calls: AtomicUsize::new(0),
+        })
+    }
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(name);
+        let _ = std::fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn second_request_is_served_from_disk() {
+        let dir = temp_dir("moron_test_tts_cache_hit");
+        let inner = counting();
+        let cache = CachingBackend::new(inner.clone(), &dir);
+
+        assert_eq!(cache.is_cached("hello"), Some(false));
+        let first = cache.synthesize("hello").unwrap();
+        assert_eq!(cache.is_cached("hello"), Some(true));
+        let second = cache.synthesize("hello").unwrap();
+
+        assert_eq!(inner.calls.load(Ordering::SeqCst), 1);
+        assert_eq!(second.data.len(), first.data.len());
+        assert_eq!(second.sample_rate, 1000);
+        assert!((second.data[0] - 0.25).abs() < 1e-4);
+
+        // A new backend over the same directory hits too.
+        let fresh = CachingBackend::new(inner.clone(), &dir);
+        fresh.synthesize("hello").unwrap();
+        assert_eq!(inner.calls.load(Ordering::SeqCst), 1);
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn key_covers_text_voice_and_backend() {
+        let inner = counting();
+        let base = CachingBackend::new(inner.clone(), "cache");
+        assert_eq!(base.key("hello"), base.key("hello"));
+        assert_eq!(base.key("hello").len(), 16);
+        assert_ne!(base.key("hello"), base.key("hello!"));
+
+        let voice = Voice::kokoro();
+        let plain = CachingBackend::new(inner.clone(), "cache").with_voice(&voice);
+        let faster = CachingBackend::new(inner.clone(), "cache")
+            .with_voice(&voice.clone().with_speed(1.2));
+        let other = CachingBackend::new(inner.clone(), "cache")
+            .with_voice(&voice.clone().with_kokoro_voice(crate::KokoroVoice::BmLewis));
+        assert_ne!(base.key("hello"), plain.key("hello"));
+        assert_ne!(plain.key("hello"), faster.key("hello"));
+        assert_ne!(plain.key("hello"), other.key("hello"));
+
+        let recorded = crate::PreRecordedBackend::from_clip("vo.wav", AudioClip::silence(0.1, 1000), &[]);
+        let other_backend = CachingBackend::new(Arc::new(recorded), "cache");
+        assert_ne!(base.key("hello"), other_backend.key("hello"));
+    }
+
+    #[test]
+    fn recordings_pass_through() {
+        let dir = temp_dir("moron_test_tts_cache_recording");
+        let recording = AudioClip {
+            duration: 0.004,
+            data: vec![0.1, 0.1, 0.2, 0.2],
+            sample_rate: 1000,
+            channels: 1,
+        };
+        let recorded = crate::PreRecordedBackend::from_clip("vo.wav", recording, &[2]);
+        let cache = CachingBackend::new(Arc::new(recorded), &dir);
+
+        assert_eq!(cache.recorded_sections(), Some(2));
+        assert_eq!(cache.is_cached("same"), None);
+        // Same text, different sections: nothing is served from disk.
+        let first = cache.synthesize("same").unwrap();
+        let second = cache.synthesize("same").unwrap();
+        assert!((first.data[0] - 0.1).abs() < 1e-6);
+        assert!((second.data[0] - 0.2).abs() < 1e-6);
+        assert!(!dir.exists());
+    }
+
+    #[test]
+    fn key_covers_model_file() {
+        let dir = temp_dir("moron_test_tts_cache_model");
+        std::fs::create_dir_all(&dir).unwrap();
+        let model = dir.join("model.onnx");
+        std::fs::write(&model, b"v1").unwrap();
+
+        let inner = counting();
+        let before = CachingBackend::new(inner.clone(), &dir).with_model(&model);
+        std::fs::write(&model, b"v2 is larger").unwrap();
+        let after = CachingBackend::new(inner.clone(), &dir).with_model(&model);
+        assert_ne!(before.key("hello"), after.key("hello"));
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn corrupt_entry_is_a_miss() {
+        let dir = temp_dir("moron_test_tts_cache_corrupt");
+        let inner = counting();
+        let cache = CachingBackend::new(inner.clone(), &dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(cache.path_for("hello"), b"not a wav").unwrap();
+
+        let clip = cache.synthesize("hello").unwrap();
+        assert_eq!(clip.data.len(), 5);
+        assert_eq!(inner.calls.load(Ordering::SeqCst), 1);
+        // The bad entry was replaced.
+        cache.synthesize("hello").unwrap();
+        assert_eq!(inner.calls.load(Ordering::SeqCst), 1);
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
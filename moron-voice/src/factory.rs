@@ -0,0 +1,348 @@
+//! Backend factory: turn a [`Voice`] description into a live [`VoiceBackend`].
+//!
+//! A [`Voice`] only says *which* engine to use and how it should sound. The
+//! factory pairs it with machine-specific settings from [`BackendConfig`]
+//! (model paths and the like) to construct the backend.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use moron_voice::{create_backend, BackendConfig, Voice};
+//!
+//! let config = BackendConfig::from_env();
+//! let backend = create_backend(&Voice::kokoro(), &config).unwrap();
+//! let clip = backend.synthesize("Hello, world!").unwrap();
+//! ```
+
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use crate::backend::{Voice, VoiceBackend, VoiceBackendType};
+use crate::kokoro::KokoroError;
+use crate::piper::{PiperBackend, PiperConfig, PiperError};
+use crate::prerecorded::{PreRecordedBackend, PreRecordedError};
+
+/// Environment variable holding the path to the Kokoro ONNX model.
+pub const KOKORO_MODEL_ENV: &str = "KOKORO_MODEL_PATH";
+
+/// Environment variable holding the path to the Kokoro voices file.
+pub const KOKORO_VOICES_ENV: &str = "KOKORO_VOICES_PATH";
+
+/// Environment variable holding the path to the Piper voice model.
+pub const PIPER_MODEL_ENV: &str = "PIPER_MODEL_PATH";
+
+/// Environment variable naming the piper executable (defaults to `piper`).
+pub const PIPER_EXECUTABLE_ENV: &str = "PIPER_EXECUTABLE";
+
+// ---------------------------------------------------------------------------
+// Errors
+// ---------------------------------------------------------------------------
+
+/// Errors produced while constructing a backend for a [`Voice`].
+#[derive(Debug, thiserror::Error)]
+pub enum BackendError {
+    /// A setting the backend needs was not provided.
+    #[error("{backend} backend is not configured: {reason}")]
+    NotConfigured {
+        /// Name of the backend (e.g. "kokoro").
+        backend: &'static str,
+        /// What is missing and how to provide it.
+        reason: String,
+    },
+
+    /// The voice asks for a backend this build cannot construct.
+    #[error("{0} voices are not supported")]
+    Unsupported(String),
+
+    /// The Kokoro backend rejected its configuration.
+    #[error(transparent)]
+    Kokoro(#[from] KokoroError),
+
+    /// The Piper backend rejected its configuration.
+    #[error(transparent)]
+    Piper(#[from] PiperError),
+
+    /// The pre-recorded voiceover could not be loaded.
+    #[error(transparent)]
+    PreRecorded(#[from] PreRecordedError),
+}
+
+// ---------------------------------------------------------------------------
+// Config
+// ---------------------------------------------------------------------------
+
+/// Machine-specific settings used to construct voice backends.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct BackendConfig {
+    /// Path to the Kokoro ONNX model file.
+    pub kokoro_model_path: Option<PathBuf>,
+    /// Path to the Kokoro voices data file.
+    pub kokoro_voices_path: Option<PathBuf>,
+    /// Path to the Piper voice model (`.onnx`).
+    pub piper_model_path: Option<PathBuf>,
+    /// The piper executable; `piper` on `PATH` when `None`.
+    pub piper_executable: Option<PathBuf>,
+}
+
+impl BackendConfig {
+    /// Create an empty config. No backend can be constructed until the
+    /// relevant settings are filled in.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Read settings from [`KOKORO_MODEL_ENV`], [`KOKORO_VOICES_ENV`],
+    /// [`PIPER_MODEL_ENV`], and [`PIPER_EXECUTABLE_ENV`].
+    pub fn from_env() -> Self {
+        let path = |name: &str| std::env::var_os(name).map(PathBuf::from);
+        Self {
+            kokoro_model_path: path(KOKORO_MODEL_ENV),
+            kokoro_voices_path: path(KOKORO_VOICES_ENV),
+            piper_model_path: path(PIPER_MODEL_ENV),
+            piper_executable: path(PIPER_EXECUTABLE_ENV),
+        }
+    }
+
+    /// Set the Kokoro model and voices paths.
+    pub fn with_kokoro(
+        mut self,
+        model_path: impl Into<PathBuf>,
+        voices_path: impl Into<PathBuf>,
+    ) -> Self {
+        self.kokoro_model_path = Some(model_path.into());
+        self.kokoro_voices_path = Some(voices_path.into());
+        self
+    }
+
+    /// Set the Piper voice model path.
+    pub fn with_piper(mut self, model_path: impl Into<PathBuf>) -> Self {
+        self.piper_model_path = Some(model_path.into());
+        self
+    }
+
+    /// Whether the Kokoro model and voices paths are both set.
+    pub fn has_kokoro(&self) -> bool {
+        self.kokoro_model_path.is_some() && self.kokoro_voices_path.is_some()
+    }
+
+    /// Whether a Piper voice model is set.
+    pub fn has_piper(&self) -> bool {
+        self.piper_model_path.is_some()
+    }
+
+    /// The voice for narrations that don't pick one: Kokoro if it is
+    /// configured, else Piper, or `None` if neither engine is.
+    pub fn default_voice(&self) -> Option<Voice> {
+        if self.has_kokoro() {
+            Some(Voice::kokoro())
+        } else if self.has_piper() {
+            Some(Voice::piper())
+        } else {
+            None
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Factory
+// ---------------------------------------------------------------------------
+
+/// Construct the backend described by `voice`.
+///
+/// Applies the voice's `speed` and its Kokoro or Piper speaker. `pitch` is
+/// not supported by any backend yet and is ignored. Pre-recorded voices need
+/// no config; their recording is loaded here.
+///
+/// # Errors
+///
+/// Returns [`BackendError::NotConfigured`] if `config` lacks a setting the
+/// backend needs, [`BackendError::Kokoro`] or [`BackendError::Piper`] if
+/// the model files are missing, [`BackendError::PreRecorded`] if a
+/// recording can't be loaded, and [`BackendError::Unsupported`] for backend
+/// types that can't be constructed.
+pub fn create_backend(
+    voice: &Voice,
+    config: &BackendConfig,
+) -> Result<Arc<dyn VoiceBackend + Send + Sync>, BackendError> {
+    match &voice.backend_type {
+        VoiceBackendType::Kokoro => create_kokoro(voice, config),
+        VoiceBackendType::Piper => create_piper(voice, config),
+        VoiceBackendType::ApiProvider(name) => {
+            Err(BackendError::Unsupported(format!("API provider ({name})")))
+        }
+        VoiceBackendType::PreRecorded(path) => Ok(Arc::new(PreRecordedBackend::open(path)?)),
+    }
+}
+
+fn create_piper(
+    voice: &Voice,
+    config: &BackendConfig,
+) -> Result<Arc<dyn VoiceBackend + Send + Sync>, BackendError> {
+    let Some(model) = &config.piper_model_path else {
+        return Err(BackendError::NotConfigured {
+            backend: "piper",
+            reason: format!("set {PIPER_MODEL_ENV} to the voice model file"),
+        });
+    };
+
+    // The sample rate comes from the model's `.onnx.json` config.
+    let mut piper_config = PiperConfig::from_model(model)?;
+    if let Some(executable) = &config.piper_executable {
+        piper_config = piper_config.with_executable(executable);
+    }
+    if let Some(speaker) = voice.piper_speaker {
+        piper_config = piper_config.with_speaker(speaker);
+    }
+    // Piper stretches phonemes, so faster speech means a shorter length.
+    if voice.speed > 0.0 {
+        piper_config = piper_config.with_length_scale((1.0 / voice.speed) as f32);
+    }
+
+    Ok(Arc::new(PiperBackend::new(piper_config)))
+}
+
+#[cfg(feature = "kokoro")]
+fn create_kokoro(
+    voice: &Voice,
+    config: &BackendConfig,
+) -> Result<Arc<dyn VoiceBackend + Send + Sync>, BackendError> {
+    use crate::kokoro::{KokoroBackend, KokoroConfig};
+
+    let (Some(model), Some(voices)) = (&config.kokoro_model_path, &config.kokoro_voices_path)
+    else {
+        return Err(BackendError::NotConfigured {
+            backend: "kokoro",
+            reason: format!(
+                "set {KOKORO_MODEL_ENV} and {KOKORO_VOICES_ENV} to the model and voices files"
+            ),
+        });
+    };
+
+    let kokoro_config = KokoroConfig::new(model, voices)
+        .with_voice(voice.kokoro_voice)
+        .with_speed(voice.speed as f32);
+    kokoro_config.validate()?;
+
+    Ok(Arc::new(KokoroBackend::new(kokoro_config)?))
+}
+
+#[cfg(not(feature = "kokoro"))]
+fn create_kokoro(
+    _voice: &Voice,
+    _config: &BackendConfig,
+) -> Result<Arc<dyn VoiceBackend + Send + Sync>, BackendError> {
+    Err(BackendError::Unsupported(
+        "kokoro (compile with the `kokoro` feature enabled)".to_string(),
+    ))
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn config_with_kokoro_sets_paths() {
+        let config = BackendConfig::new().with_kokoro("/m/kokoro.onnx", "/m/voices.bin");
+        assert!(config.has_kokoro());
+        assert_eq!(config.kokoro_model_path, Some(PathBuf::from("/m/kokoro.onnx")));
+        assert_eq!(config.kokoro_voices_path, Some(PathBuf::from("/m/voices.bin")));
+        assert!(!BackendConfig::new().has_kokoro());
+    }
+
+    #[test]
+    fn default_voice_prefers_configured_engine() {
+        assert_eq!(BackendConfig::new().default_voice(), None);
+
+        let piper = BackendConfig::new().with_piper("/m/voice.onnx");
+        assert_eq!(piper.default_voice(), Some(Voice::piper()));
+
+        let both = piper.with_kokoro("/m/kokoro.onnx", "/m/voices.bin");
+        assert_eq!(both.default_voice(), Some(Voice::kokoro()));
+    }
+
+    #[test]
+    fn unsupported_backend_types() {
+        let config = BackendConfig::new();
+        let mut api = Voice::kokoro();
+        api.backend_type = VoiceBackendType::ApiProvider("elevenlabs".into());
+        let err = create_backend(&api, &config).err().unwrap();
+        assert!(err.to_string().contains("elevenlabs"));
+    }
+
+    #[cfg(feature = "kokoro")]
+    #[test]
+    fn kokoro_requires_paths() {
+        let err = create_backend(&Voice::kokoro(), &BackendConfig::new())
+            .err()
+            .unwrap();
+        assert!(matches!(err, BackendError::NotConfigured { backend: "kokoro", .. }));
+        assert!(err.to_string().contains(KOKORO_MODEL_ENV));
+    }
+
+    #[cfg(feature = "kokoro")]
+    #[test]
+    fn kokoro_missing_model_fails_fast() {
+        let config = BackendConfig::new()
+            .with_kokoro("/nonexistent/kokoro.onnx", "/nonexistent/voices.bin");
+        let err = create_backend(&Voice::kokoro(), &config).err().unwrap();
+        assert!(matches!(err, BackendError::Kokoro(KokoroError::ModelNotFound(_))));
+    }
+
+    #[test]
+    fn piper_requires_model() {
+        let err = create_backend(&Voice::piper(), &BackendConfig::new())
+            .err()
+            .unwrap();
+        assert!(matches!(err, BackendError::NotConfigured { backend: "piper", .. }));
+        assert!(err.to_string().contains(PIPER_MODEL_ENV));
+
+        let config = BackendConfig::new().with_piper("/nonexistent/voice.onnx");
+        let err = create_backend(&Voice::piper(), &config).err().unwrap();
+        assert!(matches!(err, BackendError::Piper(PiperError::ModelNotFound(_))));
+    }
+
+    #[test]
+    fn piper_backend_from_voice() {
+        let dir = std::env::temp_dir().join("moron_test_factory_piper");
+        std::fs::create_dir_all(&dir).unwrap();
+        let model = dir.join("voice.onnx");
+        std::fs::write(&model, b"fake").unwrap();
+
+        let config = BackendConfig::new().with_piper(&model);
+        let voice = Voice::piper().with_speed(2.0).with_piper_speaker(4);
+        // Piper needs the model's config for its sample rate.
+        let err = create_backend(&voice, &config).err().unwrap();
+        assert!(matches!(err, BackendError::Piper(PiperError::InvalidModelConfig { .. })));
+
+        std::fs::write(dir.join("voice.onnx.json"), r#"{"audio": {"sample_rate": 22050}}"#)
+            .unwrap();
+        let backend = create_backend(&voice, &config).unwrap();
+        assert_eq!(backend.name(), "piper");
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn pre_recorded_backend_from_voice() {
+        let dir = std::env::temp_dir().join("moron_test_factory_prerecorded");
+        std::fs::create_dir_all(&dir).unwrap();
+        let wav = dir.join("vo.wav");
+        std::fs::write(&wav, crate::AudioClip::silence(0.5, 8000).to_wav_bytes()).unwrap();
+
+        let backend = create_backend(&Voice::file(&wav), &BackendConfig::new()).unwrap();
+        assert_eq!(backend.name(), "pre-recorded");
+        let clip = backend.synthesize("ignored").unwrap();
+        assert!((clip.duration() - 0.5).abs() < 1e-9);
+
+        let err = create_backend(&Voice::file(dir.join("missing.wav")), &BackendConfig::new())
+            .err()
+            .unwrap();
+        assert!(matches!(err, BackendError::PreRecorded(PreRecordedError::Io { .. })));
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
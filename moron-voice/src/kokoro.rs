@@ -100,21 +100,22 @@ pub enum KokoroVoice {
 
 #[cfg(feature = "kokoro")]
 impl KokoroVoice {
-    /// Convert to the upstream `kokoro_tts::Voice` enum variant.
-    fn to_kokoro_tts_voice(self) -> kokoro_tts::Voice {
+    /// Convert to the upstream `kokoro_tts::Voice` enum variant, which also
+    /// carries the speech speed.
+    fn to_kokoro_tts_voice(self, speed: f32) -> kokoro_tts::Voice {
         match self {
-            Self::AfHeart => kokoro_tts::Voice::AfHeart(1.0),
-            Self::AfSky => kokoro_tts::Voice::AfSky(1.0),
-            Self::AfBella => kokoro_tts::Voice::AfBella(1.0),
-            Self::AfNova => kokoro_tts::Voice::AfNova(1.0),
-            Self::AfSarah => kokoro_tts::Voice::AfSarah(1.0),
-            Self::AmAdam => kokoro_tts::Voice::AmAdam(1.0),
-            Self::AmPuck => kokoro_tts::Voice::AmPuck(1.0),
-            Self::AmEric => kokoro_tts::Voice::AmEric(1.0),
-            Self::AmMichael => kokoro_tts::Voice::AmMichael(1.0),
-            Self::BfEmma => kokoro_tts::Voice::BfEmma(1.0),
-            Self::BmGeorge => kokoro_tts::Voice::BmGeorge(1.0),
-            Self::BmLewis => kokoro_tts::Voice::BmLewis(1.0),
+            Self::AfHeart => kokoro_tts::Voice::AfHeart(speed),
+            Self::AfSky => kokoro_tts::Voice::AfSky(speed),
+            Self::AfBella => kokoro_tts::Voice::AfBella(speed),
+            Self::AfNova => kokoro_tts::Voice::AfNova(speed),
+            Self::AfSarah => kokoro_tts::Voice::AfSarah(speed),
+            Self::AmAdam => kokoro_tts::Voice::AmAdam(speed),
+            Self::AmPuck => kokoro_tts::Voice::AmPuck(speed),
+            Self::AmEric => kokoro_tts::Voice::AmEric(speed),
+            Self::AmMichael => kokoro_tts::Voice::AmMichael(speed),
+            Self::BfEmma => kokoro_tts::Voice::BfEmma(speed),
+            Self::BmGeorge => kokoro_tts::Voice::BmGeorge(speed),
+            Self::BmLewis => kokoro_tts::Voice::BmLewis(speed),
         }
     }
 }
@@ -252,7 +253,7 @@ mod inner {
                 KokoroError::SynthesisFailed(format!("engine lock poisoned: {}", e))
             })?;
             let engine = guard.as_ref().expect("engine must be loaded after ensure_loaded");
-            let voice = self.config.voice.to_kokoro_tts_voice();
+            let voice = self.config.voice.to_kokoro_tts_voice(self.config.speed);
 
             let (samples, duration) = self.runtime.block_on(async {
                 engine.synth(text, voice).await
@@ -5,12 +5,21 @@
 pub mod alignment;
 pub mod audio;
 pub mod backend;
+pub mod cache;
+pub mod factory;
 pub mod kokoro;
+pub mod mixer;
 pub mod piper;
+pub mod prerecorded;
 
+pub use alignment::WordTiming;
 pub use audio::{AudioClip, AudioError, DEFAULT_SAMPLE_RATE};
 pub use backend::{Voice, VoiceBackend, VoiceBackendType};
-pub use kokoro::KokoroBackend;
+pub use cache::CachingBackend;
+pub use factory::{create_backend, BackendConfig, BackendError};
+pub use kokoro::{KokoroBackend, KokoroError, KokoroVoice};
+pub use mixer::{Ducking, MixClip, Mixer};
 #[cfg(feature = "kokoro")]
-pub use kokoro::{KokoroConfig, KokoroError, KokoroVoice, KOKORO_SAMPLE_RATE};
-pub use piper::PiperBackend;
+pub use kokoro::{KokoroConfig, KOKORO_SAMPLE_RATE};
+pub use piper::{PiperBackend, PiperConfig, PiperError, PIPER_SAMPLE_RATE};
+pub use prerecorded::{PreRecordedBackend, PreRecordedError};
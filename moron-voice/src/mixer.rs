@@ -0,0 +1,491 @@
+//! Multi-clip mixer: overlay clips at arbitrary offsets into one track.
+//!
+//! Unlike [`AudioClip::concat`], which only joins clips end to end, the
+//! mixer places each clip at its own start time with its own gain and
+//! fades, summing wherever clips overlap. A clip can also be [ducked](Ducking)
+//! under given time ranges, e.g. lowering music while someone speaks. Clips
+//! in a different format are converted to the mixer's sample rate and
+//! channel layout first.
+//!
+//! # Example
+//!
+//! ```
+//! use moron_voice::{AudioClip, MixClip, Mixer};
+//!
+//! let voice = AudioClip::silence(2.0, 48000);
+//! let music = AudioClip::silence(10.0, 44100);
+//!
+//! let mut mixer = Mixer::stereo(48000);
+//! mixer.add(MixClip::new(music, 0.0).with_gain(0.3).with_fade_out(2.0));
+//! mixer.add(MixClip::new(voice, 1.5));
+//! let track = mixer.mix();
+//! assert!((track.duration() - 10.0).abs() < 1e-9);
+//! ```
+
+use std::ops::Range;
+
+use crate::audio::AudioClip;
+
+// ---------------------------------------------------------------------------
+// Ducking
+// ---------------------------------------------------------------------------
+
+/// Lowers a clip's level during given time ranges, typically under speech.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Ducking {
+    /// Gain multiplier while ducked (0.0 = silent, 1.0 = no ducking).
+    pub level: f32,
+    /// Seconds to ramp down, ending as each range starts.
+    pub attack: f64,
+    /// Seconds to ramp back up after each range ends.
+    pub release: f64,
+    /// Time ranges, in seconds on the mixer's timeline, to duck under.
+    pub ranges: Vec<Range<f64>>,
+}
+
+impl Default for Ducking {
+    /// Duck to a quarter of the level with a 0.25s attack and 0.5s release.
+    fn default() -> Self {
+        Self {
+            level: 0.25,
+            attack: 0.25,
+            release: 0.5,
+            ranges: Vec::new(),
+        }
+    }
+}
+
+impl Ducking {
+    /// Set the gain multiplier while ducked.
+    pub fn with_level(mut self, level: f32) -> Self {
+        self.level = level.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Set the ramp-down time in seconds.
+    pub fn with_attack(mut self, seconds: f64) -> Self {
+        self.attack = seconds.max(0.0);
+        self
+    }
+
+    /// Set the ramp-up time in seconds.
+    pub fn with_release(mut self, seconds: f64) -> Self {
+        self.release = seconds.max(0.0);
+        self
+    }
+
+    /// Set the time ranges to duck under.
+    pub fn with_ranges(mut self, ranges: Vec<Range<f64>>) -> Self {
+        self.ranges = ranges;
+        self
+    }
+
+    /// Gain multiplier at `time` seconds.
+    ///
+    /// For a whole track, [`Mixer`] walks the ranges once instead of calling
+    /// this per sample.
+    pub fn gain_at(&self, time: f64) -> f32 {
+        DuckingCursor::new(self).gain_at(time)
+    }
+}
+
+/// Evaluates a [`Ducking`] at increasing times in a single pass.
+///
+/// The ranges are sorted and overlapping ones merged up front. Only the
+/// range the cursor is in or approaching (attack) and the one it just left
+/// (release) can affect the gain, since the nearest edge dominates the ramp.
+struct DuckingCursor<'a> {
+    ducking: &'a Ducking,
+    /// Disjoint ranges in time order.
+    ranges: Vec<Range<f64>>,
+    /// Index of the first range that ends after the last queried time.
+    next: usize,
+}
+
+impl<'a> DuckingCursor<'a> {
+    fn new(ducking: &'a Ducking) -> Self {
+        let mut sorted = ducking.ranges.clone();
+        sorted.sort_by(|a, b| a.start.total_cmp(&b.start));
+        let mut ranges: Vec<Range<f64>> = Vec::with_capacity(sorted.len());
+        for range in sorted {
+            match ranges.last_mut() {
+                Some(last) if range.start <= last.end => last.end = last.end.max(range.end),
+                _ => ranges.push(range),
+            }
+        }
+        Self {
+            ducking,
+            ranges,
+            next: 0,
+        }
+    }
+
+    /// Gain multiplier at `time` seconds, which must not be earlier than the
+    /// previous call's.
+    fn gain_at(&mut self, time: f64) -> f32 {
+        while self.ranges.get(self.next).is_some_and(|r| r.end <= time) {
+            self.next += 1;
+        }
+
+        // How far into the duck we are, 0.0 (none) to 1.0 (fully ducked).
+        let upcoming = self.ranges.get(self.next);
+        let depth = if upcoming.is_some_and(|r| r.contains(&time)) {
+            1.0
+        } else {
+            let attack = upcoming.map_or(0.0, |r| ramp(r.start - time, self.ducking.attack));
+            let release = self.next.checked_sub(1).map_or(0.0, |i| {
+                ramp(time - self.ranges[i].end, self.ducking.release)
+            });
+            attack.max(release)
+        };
+        1.0 - depth as f32 * (1.0 - self.ducking.level)
+    }
+}
+
+/// Linear ramp from 1.0 at `distance` 0 to 0.0 at `length`.
+fn ramp(distance: f64, length: f64) -> f64 {
+    if distance >= length {
+        0.0
+    } else {
+        1.0 - distance / length
+    }
+}
+
+// ---------------------------------------------------------------------------
+// MixClip
+// ---------------------------------------------------------------------------
+
+/// A clip placed on the mixer's timeline.
+#[derive(Debug, Clone)]
+pub struct MixClip {
+    /// The audio to place.
+    pub clip: AudioClip,
+    /// Start time in seconds. A negative offset drops the clip's beginning.
+    pub offset: f64,
+    /// Linear gain applied to every sample (1.0 = unchanged).
+    pub gain: f32,
+    /// Seconds over which the clip ramps up from silence.
+    pub fade_in: f64,
+    /// Seconds over which the clip ramps down to silence at its end.
+    pub fade_out: f64,
+    /// Level reduction under other audio, if any.
+    pub ducking: Option<Ducking>,
+}
+
+impl MixClip {
+    /// Place `clip` at `offset` seconds with unity gain and no fades.
+    pub fn new(clip: AudioClip, offset: f64) -> Self {
+        Self {
+            clip,
+            offset,
+            gain: 1.0,
+            fade_in: 0.0,
+            fade_out: 0.0,
+            ducking: None,
+        }
+    }
+
+    /// Set the linear gain.
+    pub fn with_gain(mut self, gain: f32) -> Self {
+        self.gain = gain;
+        self
+    }
+
+    /// Set the fade-in length in seconds.
+    pub fn with_fade_in(mut self, seconds: f64) -> Self {
+        self.fade_in = seconds.max(0.0);
+        self
+    }
+
+    /// Set the fade-out length in seconds.
+    pub fn with_fade_out(mut self, seconds: f64) -> Self {
+        self.fade_out = seconds.max(0.0);
+        self
+    }
+
+    /// Duck the clip under `ducking`'s time ranges.
+    pub fn with_ducking(mut self, ducking: Ducking) -> Self {
+        self.ducking = Some(ducking);
+        self
+    }
+
+    /// Gain for `frame` of a clip `frames` long, including fades. Ducking is
+    /// applied by the mixer, which knows the frame's time on the track.
+    fn gain_at(&self, frame: usize, frames: usize, sample_rate: u32) -> f32 {
+        let rate = f64::from(sample_rate);
+        let mut gain = self.gain;
+
+        let fade_in = (self.fade_in * rate) as usize;
+        if frame < fade_in {
+            gain *= frame as f32 / fade_in as f32;
+        }
+        let fade_out = (self.fade_out * rate) as usize;
+        let remaining = frames - frame;
+        if remaining <= fade_out {
+            gain *= (remaining - 1) as f32 / fade_out as f32;
+        }
+        gain
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Mixer
+// ---------------------------------------------------------------------------
+
+/// Sums [`MixClip`]s into a single mono or stereo track.
+#[derive(Debug, Clone)]
+pub struct Mixer {
+    sample_rate: u32,
+    channels: u16,
+    duration: f64,
+    clips: Vec<MixClip>,
+}
+
+impl Mixer {
+    /// Create a mixer producing a mono track at `sample_rate`.
+    pub fn mono(sample_rate: u32) -> Self {
+        Self::with_channels(sample_rate, 1)
+    }
+
+    /// Create a mixer producing a stereo track at `sample_rate`.
+    pub fn stereo(sample_rate: u32) -> Self {
+        Self::with_channels(sample_rate, 2)
+    }
+
+    fn with_channels(sample_rate: u32, channels: u16) -> Self {
+        Self {
+            sample_rate,
+            channels,
+            duration: 0.0,
+            clips: Vec::new(),
+        }
+    }
+
+    /// Set the minimum length of the mixed track in seconds. The track is
+    /// still extended if a clip runs past it.
+    pub fn with_duration(mut self, seconds: f64) -> Self {
+        self.duration = seconds.max(0.0);
+        self
+    }
+
+    /// Add a clip to the mix.
+    pub fn add(&mut self, clip: MixClip) {
+        self.clips.push(clip);
+    }
+
+    /// Sum all clips into one track.
+    ///
+    /// Samples are not clamped; overlapping loud clips can exceed
+    /// [-1.0, 1.0] until the track is written out.
+    pub fn mix(&self) -> AudioClip {
+        let channels = usize::from(self.channels);
+        let rate = f64::from(self.sample_rate);
+        let mut data = vec![0.0; (self.duration * rate) as usize * channels];
+
+        for input in &self.clips {
+            let converted;
+            let clip = if input.clip.sample_rate == self.sample_rate
+                && input.clip.channels == self.channels
+            {
+                &input.clip
+            } else {
+                converted = self.convert(&input.clip);
+                &converted
+            };
+
+            let frames = clip.data.len() / channels;
+            let start = (input.offset * rate).round() as i64;
+            let skip = usize::try_from(-start).unwrap_or(0);
+            if skip >= frames {
+                continue;
+            }
+            let first = start.max(0) as usize;
+            let end = (first + frames - skip) * channels;
+            if data.len() < end {
+                data.resize(end, 0.0);
+            }
+
+            let mut ducking = input.ducking.as_ref().map(DuckingCursor::new);
+            for frame in skip..frames {
+                let position = first + frame - skip;
+                let mut gain = input.gain_at(frame, frames, self.sample_rate);
+                if let Some(ducking) = &mut ducking {
+                    gain *= ducking.gain_at(position as f64 / rate);
+                }
+                let src = &clip.data[frame * channels..(frame + 1) * channels];
+                let at = position * channels;
+                for (dst, &sample) in data[at..at + channels].iter_mut().zip(src) {
+                    *dst += sample * gain;
+                }
+            }
+        }
+
+        AudioClip {
+            duration: data.len() as f64 / (rate * channels as f64),
+            data,
+            sample_rate: self.sample_rate,
+            channels: self.channels,
+        }
+    }
+
+    /// Convert `clip` to the mixer's channel layout and sample rate.
+    fn convert(&self, clip: &AudioClip) -> AudioClip {
+        let remixed = if self.channels == 1 {
+            clip.to_mono()
+        } else {
+            clip.to_stereo()
+        };
+        remixed.resample(self.sample_rate)
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A mono clip of `frames` samples at 1 kHz, all set to `value`.
+    fn constant(value: f32, frames: usize) -> AudioClip {
+        AudioClip {
+            data: vec![value; frames],
+            duration: frames as f64 / 1000.0,
+            sample_rate: 1000,
+            channels: 1,
+        }
+    }
+
+    #[test]
+    fn empty_mixer_uses_duration() {
+        let track = Mixer::mono(1000).with_duration(0.5).mix();
+        assert_eq!(track.data.len(), 500);
+        assert!(track.data.iter().all(|&s| s == 0.0));
+        assert!((track.duration() - 0.5).abs() < 1e-12);
+    }
+
+    #[test]
+    fn clips_overlap_and_sum() {
+        let mut mixer = Mixer::mono(1000).with_duration(0.1);
+        mixer.add(MixClip::new(constant(0.25, 100), 0.0));
+        mixer.add(MixClip::new(constant(0.5, 100), 0.05));
+        let track = mixer.mix();
+
+        assert_eq!(track.data.len(), 150);
+        assert_eq!(track.data[49], 0.25);
+        assert_eq!(track.data[50], 0.75);
+        assert_eq!(track.data[99], 0.75);
+        assert_eq!(track.data[100], 0.5);
+    }
+
+    #[test]
+    fn gain_and_fades() {
+        let mut mixer = Mixer::mono(1000);
+        mixer.add(
+            MixClip::new(constant(1.0, 100), 0.0)
+                .with_gain(0.5)
+                .with_fade_in(0.01)
+                .with_fade_out(0.01),
+        );
+        let track = mixer.mix();
+
+        assert_eq!(track.data[0], 0.0);
+        assert!((track.data[5] - 0.25).abs() < 1e-6);
+        assert_eq!(track.data[50], 0.5);
+        assert!((track.data[94] - 0.25).abs() < 1e-6);
+        assert_eq!(track.data[99], 0.0);
+    }
+
+    #[test]
+    fn ducking_envelope() {
+        let ducking = Ducking::default()
+            .with_level(0.2)
+            .with_attack(1.0)
+            .with_release(2.0)
+            .with_ranges(vec![3.0..5.0]);
+
+        assert_eq!(ducking.gain_at(0.0), 1.0);
+        assert!((ducking.gain_at(2.5) - 0.6).abs() < 1e-6);
+        assert!((ducking.gain_at(4.0) - 0.2).abs() < 1e-6);
+        assert!((ducking.gain_at(6.0) - 0.6).abs() < 1e-6);
+        assert_eq!(ducking.gain_at(7.0), 1.0);
+    }
+
+    #[test]
+    fn ducking_cursor_matches_every_range() {
+        let ducking = Ducking::default()
+            .with_attack(0.5)
+            .with_release(1.0)
+            .with_ranges(vec![6.0..7.0, 1.0..2.0, 1.5..3.0, 3.2..3.4, 9.0..9.0]);
+
+        // Reference: the deepest duck over all ranges, checked one by one.
+        let expected = |time: f64| {
+            let depth = ducking
+                .ranges
+                .iter()
+                .map(|range| {
+                    if range.contains(&time) {
+                        1.0
+                    } else if time < range.start {
+                        ramp(range.start - time, ducking.attack)
+                    } else {
+                        ramp(time - range.end, ducking.release)
+                    }
+                })
+                .fold(0.0, f64::max);
+            1.0 - depth as f32 * (1.0 - ducking.level)
+        };
+
+        let mut cursor = DuckingCursor::new(&ducking);
+        for step in 0..1100 {
+            let time = step as f64 / 100.0;
+            assert!((cursor.gain_at(time) - expected(time)).abs() < 1e-6, "at {time}");
+        }
+    }
+
+    #[test]
+    fn ducking_uses_track_time() {
+        let ducking = Ducking::default()
+            .with_level(0.5)
+            .with_attack(0.0)
+            .with_release(0.0)
+            .with_ranges(vec![0.06..0.08]);
+        let mut mixer = Mixer::mono(1000);
+        mixer.add(MixClip::new(constant(1.0, 50), 0.05).with_ducking(ducking));
+        let track = mixer.mix();
+
+        assert_eq!(track.data[55], 1.0);
+        assert_eq!(track.data[65], 0.5);
+        assert_eq!(track.data[85], 1.0);
+    }
+
+    #[test]
+    fn negative_offset_trims_start() {
+        let clip = AudioClip {
+            data: (0..10).map(|i| i as f32).collect(),
+            duration: 0.01,
+            sample_rate: 1000,
+            channels: 1,
+        };
+        let mut mixer = Mixer::mono(1000);
+        mixer.add(MixClip::new(clip.clone(), -0.004));
+        mixer.add(MixClip::new(clip, -1.0));
+        let track = mixer.mix();
+        assert_eq!(track.data, vec![4.0, 5.0, 6.0, 7.0, 8.0, 9.0]);
+    }
+
+    #[test]
+    fn converts_clip_format() {
+        let mut mixer = Mixer::stereo(2000);
+        mixer.add(MixClip::new(constant(0.5, 1000), 0.0));
+        let track = mixer.mix();
+
+        assert_eq!(track.channels, 2);
+        assert_eq!(track.sample_rate, 2000);
+        assert_eq!(track.data.len(), 2 * 2000);
+        assert!((track.duration() - 1.0).abs() < 1e-12);
+        assert!((track.data[2000] - 0.5).abs() < 1e-3);
+        assert!((track.data[2001] - 0.5).abs() < 1e-3);
+    }
+}
@@ -1,17 +1,526 @@
 //! Piper backend: fallback local TTS engine integration.
+//!
+//! Drives a local [`piper`](https://github.com/rhasspy/piper) executable as a
+//! subprocess: text is written to its stdin and raw 16-bit mono PCM is read
+//! back from stdout. Voice models (`*.onnx` plus a `*.onnx.json` config) are
+//! downloaded separately.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use moron_voice::{PiperBackend, PiperConfig, VoiceBackend};
+//!
+//! let config = PiperConfig::from_model("models/en_US-lessac-medium.onnx")
+//!     .unwrap()
+//!     .with_length_scale(0.9);
+//! let backend = PiperBackend::new(config);
+//! let clip = backend.synthesize("Hello, world!").unwrap();
+//! ```
+
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
 
 use crate::audio::AudioClip;
 use crate::backend::VoiceBackend;
 
+/// Sample rate of Piper's "medium" and "high" quality models (22.05 kHz).
+pub const PIPER_SAMPLE_RATE: u32 = 22050;
+
+// ---------------------------------------------------------------------------
+// Errors
+// ---------------------------------------------------------------------------
+
+/// Errors specific to the Piper TTS backend.
+#[derive(Debug, thiserror::Error)]
+pub enum PiperError {
+    /// The voice model file was not found at the configured path.
+    #[error("piper model not found: {0}")]
+    ModelNotFound(PathBuf),
+
+    /// The model's `.onnx.json` config is missing or has no sample rate.
+    #[error("invalid piper model config {path}: {reason}")]
+    InvalidModelConfig {
+        /// The config file that was read.
+        path: PathBuf,
+        /// What was wrong with it.
+        reason: String,
+    },
+
+    /// The piper executable could not be started.
+    #[error("failed to run piper executable {path}: {reason}")]
+    SpawnFailed {
+        /// The executable that was invoked.
+        path: PathBuf,
+        /// Why the process could not be started or talked to.
+        reason: String,
+    },
+
+    /// The piper process exited with a non-zero status.
+    #[error("piper exited with {status}: {stderr}")]
+    ProcessFailed {
+        /// Exit status description.
+        status: String,
+        /// Captured stderr output.
+        stderr: String,
+    },
+
+    /// Piper produced output that is not valid audio.
+    #[error("invalid piper output: {0}")]
+    InvalidOutput(String),
+
+    /// The input text was empty.
+    #[error("cannot synthesize empty text")]
+    EmptyText,
+}
+
+// ---------------------------------------------------------------------------
+// Config
+// ---------------------------------------------------------------------------
+
+/// Configuration for the Piper TTS backend.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PiperConfig {
+    /// The piper executable to run (looked up on `PATH` if not absolute).
+    pub executable: PathBuf,
+    /// Path to the voice model (`.onnx`).
+    pub model_path: PathBuf,
+    /// Speaker id for multi-speaker models.
+    pub speaker: Option<u32>,
+    /// Phoneme length multiplier (1.0 = normal, < 1.0 = faster speech).
+    pub length_scale: f32,
+    /// Sample rate of the model's output.
+    pub sample_rate: u32,
+}
+
+impl PiperConfig {
+    /// Create a new config for the given voice model.
+    ///
+    /// Runs `piper` from `PATH` with the default speaker, a length scale of
+    /// 1.0, and [`PIPER_SAMPLE_RATE`]. Use [`from_model`](Self::from_model)
+    /// to take the sample rate from the model instead.
+    pub fn new(model_path: impl Into<PathBuf>) -> Self {
+        Self {
+            executable: PathBuf::from("piper"),
+            model_path: model_path.into(),
+            speaker: None,
+            length_scale: 1.0,
+            sample_rate: PIPER_SAMPLE_RATE,
+        }
+    }
+
+    /// Create a config for the given voice model, reading its output sample
+    /// rate from the `<model>.onnx.json` config that piper loads with it.
+    ///
+    /// Raw PCM carries no header, so the rate must come from the model:
+    /// Piper voices ship at 16 kHz, 22.05 kHz and 24 kHz.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PiperError::ModelNotFound`] if the model does not exist, or
+    /// [`PiperError::InvalidModelConfig`] if its config can't be read or has
+    /// no `audio.sample_rate`.
+    pub fn from_model(model_path: impl Into<PathBuf>) -> Result<Self, PiperError> {
+        let config = Self::new(model_path);
+        config.validate()?;
+        let sample_rate = read_sample_rate(&config.model_config_path())?;
+        Ok(config.with_sample_rate(sample_rate))
+    }
+
+    /// Path of the model's JSON config: the model path with `.json` appended.
+    pub fn model_config_path(&self) -> PathBuf {
+        let mut path = self.model_path.clone().into_os_string();
+        path.push(".json");
+        PathBuf::from(path)
+    }
+
+    /// Set the piper executable.
+    pub fn with_executable(mut self, executable: impl Into<PathBuf>) -> Self {
+        self.executable = executable.into();
+        self
+    }
+
+    /// Set the speaker id.
+    pub fn with_speaker(mut self, speaker: u32) -> Self {
+        self.speaker = Some(speaker);
+        self
+    }
+
+    /// Set the phoneme length multiplier.
+    pub fn with_length_scale(mut self, length_scale: f32) -> Self {
+        self.length_scale = length_scale;
+        self
+    }
+
+    /// Set the model's output sample rate (see its `.onnx.json` config).
+    pub fn with_sample_rate(mut self, sample_rate: u32) -> Self {
+        self.sample_rate = sample_rate;
+        self
+    }
+
+    /// Validate that the configured model exists on disk.
+    pub fn validate(&self) -> Result<(), PiperError> {
+        if !self.model_path.exists() {
+            return Err(PiperError::ModelNotFound(self.model_path.clone()));
+        }
+        Ok(())
+    }
+
+    /// Command-line arguments passed to the piper executable.
+    fn args(&self) -> Vec<String> {
+        let mut args = vec![
+            "--model".to_string(),
+            self.model_path.to_string_lossy().to_string(),
+            "--output-raw".to_string(),
+            "--length_scale".to_string(),
+            self.length_scale.to_string(),
+        ];
+        if let Some(speaker) = self.speaker {
+            args.push("--speaker".to_string());
+            args.push(speaker.to_string());
+        }
+        args
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Backend
+// ---------------------------------------------------------------------------
+
 /// Piper TTS backend — fallback local engine.
-pub struct PiperBackend;
+pub struct PiperBackend {
+    config: PiperConfig,
+}
+
+impl PiperBackend {
+    /// Create a new Piper backend with the given configuration.
+    ///
+    /// Nothing is started until [`synthesize()`](VoiceBackend::synthesize);
+    /// each call runs the executable once.
+    pub fn new(config: PiperConfig) -> Self {
+        Self { config }
+    }
+
+    /// The configuration this backend runs with.
+    pub fn config(&self) -> &PiperConfig {
+        &self.config
+    }
+
+    fn run(&self, text: &str) -> Result<AudioClip, PiperError> {
+        if text.trim().is_empty() {
+            return Err(PiperError::EmptyText);
+        }
+        self.config.validate()?;
+
+        let spawn_error = |reason: String| PiperError::SpawnFailed {
+            path: self.config.executable.clone(),
+            reason,
+        };
+
+        let mut child = Command::new(&self.config.executable)
+            .args(self.config.args())
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .map_err(|e| spawn_error(e.to_string()))?;
+
+        // Piper synthesizes one utterance per input line.
+        let line = text.split_whitespace().collect::<Vec<_>>().join(" ");
+        {
+            let mut stdin = child
+                .stdin
+                .take()
+                .ok_or_else(|| spawn_error("stdin unavailable".to_string()))?;
+            // A process that exits without reading closes the pipe early;
+            // its exit status below explains why.
+            if let Err(e) = writeln!(stdin, "{line}")
+                && e.kind() != std::io::ErrorKind::BrokenPipe
+            {
+                return Err(spawn_error(e.to_string()));
+            }
+        }
+
+        let output = child
+            .wait_with_output()
+            .map_err(|e| spawn_error(e.to_string()))?;
+
+        if !output.status.success() {
+            let status = output
+                .status
+                .code()
+                .map(|c| format!("exit code {c}"))
+                .unwrap_or_else(|| "killed by signal".to_string());
+            return Err(PiperError::ProcessFailed {
+                status,
+                stderr: String::from_utf8_lossy(&output.stderr).trim().to_string(),
+            });
+        }
+
+        decode_output(&output.stdout, self.config.sample_rate)
+    }
+}
 
 impl VoiceBackend for PiperBackend {
-    fn synthesize(&self, _text: &str) -> Result<AudioClip, anyhow::Error> {
-        todo!("PiperBackend::synthesize not yet implemented")
+    fn synthesize(&self, text: &str) -> Result<AudioClip, anyhow::Error> {
+        Ok(self.run(text)?)
     }
 
     fn name(&self) -> &str {
         "piper"
     }
 }
+
+// ---------------------------------------------------------------------------
+// Model config
+// ---------------------------------------------------------------------------
+
+/// Read `audio.sample_rate` from a piper model config.
+fn read_sample_rate(path: &Path) -> Result<u32, PiperError> {
+    let invalid = |reason: String| PiperError::InvalidModelConfig {
+        path: path.to_path_buf(),
+        reason,
+    };
+    let text = std::fs::read_to_string(path).map_err(|e| invalid(e.to_string()))?;
+    let json: serde_json::Value =
+        serde_json::from_str(&text).map_err(|e| invalid(e.to_string()))?;
+    json["audio"]["sample_rate"]
+        .as_u64()
+        .and_then(|rate| u32::try_from(rate).ok())
+        .filter(|&rate| rate > 0)
+        .ok_or_else(|| invalid("missing audio.sample_rate".to_string()))
+}
+
+// ---------------------------------------------------------------------------
+// Output decoding
+// ---------------------------------------------------------------------------
+
+/// Decode piper's stdout: raw 16-bit little-endian mono PCM, or a WAV file
+/// when piper was built to write WAV to stdout.
+fn decode_output(bytes: &[u8], sample_rate: u32) -> Result<AudioClip, PiperError> {
+    if bytes.starts_with(b"RIFF") {
+        let clip = AudioClip::from_wav_bytes(bytes)
+            .map_err(|e| PiperError::InvalidOutput(format!("WAV output: {e}")))?;
+        if clip.data.is_empty() {
+            return Err(PiperError::InvalidOutput("no audio produced".to_string()));
+        }
+        return Ok(clip);
+    }
+
+    if bytes.is_empty() {
+        return Err(PiperError::InvalidOutput("no audio produced".to_string()));
+    }
+    if !bytes.len().is_multiple_of(2) {
+        return Err(PiperError::InvalidOutput(format!(
+            "odd number of PCM bytes ({})",
+            bytes.len()
+        )));
+    }
+
+    let data: Vec<f32> = bytes
+        .chunks_exact(2)
+        .map(|b| f32::from(i16::from_le_bytes([b[0], b[1]])) / 32768.0)
+        .collect();
+    let duration = data.len() as f64 / sample_rate as f64;
+
+    Ok(AudioClip {
+        data,
+        duration,
+        sample_rate,
+        channels: 1,
+    })
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // -- Config tests -------------------------------------------------------
+
+    #[test]
+    fn config_new_sets_defaults() {
+        let config = PiperConfig::new("/tmp/voice.onnx");
+        assert_eq!(config.executable, PathBuf::from("piper"));
+        assert_eq!(config.model_path, PathBuf::from("/tmp/voice.onnx"));
+        assert_eq!(config.speaker, None);
+        assert!((config.length_scale - 1.0).abs() < f32::EPSILON);
+        assert_eq!(config.sample_rate, PIPER_SAMPLE_RATE);
+    }
+
+    #[test]
+    fn config_args_include_speaker_and_length_scale() {
+        let config = PiperConfig::new("/tmp/voice.onnx")
+            .with_speaker(3)
+            .with_length_scale(0.8);
+        assert_eq!(
+            config.args(),
+            [
+                "--model",
+                "/tmp/voice.onnx",
+                "--output-raw",
+                "--length_scale",
+                "0.8",
+                "--speaker",
+                "3"
+            ]
+        );
+    }
+
+    #[test]
+    fn config_from_model_reads_sample_rate() {
+        let dir = std::env::temp_dir().join("moron_test_piper_model_config");
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        let model = dir.join("voice.onnx");
+        std::fs::write(&model, b"fake").unwrap();
+
+        // No config next to the model.
+        let err = PiperConfig::from_model(&model).unwrap_err();
+        assert!(matches!(err, PiperError::InvalidModelConfig { .. }));
+
+        std::fs::write(dir.join("voice.onnx.json"), r#"{"audio": {"quality": "low"}}"#).unwrap();
+        let err = PiperConfig::from_model(&model).unwrap_err();
+        assert!(err.to_string().contains("audio.sample_rate"), "got: {err}");
+
+        std::fs::write(
+            dir.join("voice.onnx.json"),
+            r#"{"audio": {"sample_rate": 16000, "quality": "low"}}"#,
+        )
+        .unwrap();
+        let config = PiperConfig::from_model(&model).unwrap();
+        assert_eq!(config.sample_rate, 16000);
+        assert_eq!(config.model_config_path(), dir.join("voice.onnx.json"));
+
+        let err = PiperConfig::from_model(dir.join("missing.onnx")).unwrap_err();
+        assert!(matches!(err, PiperError::ModelNotFound(_)));
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn config_validate_missing_model() {
+        let err = PiperConfig::new("/nonexistent/voice.onnx").validate().unwrap_err();
+        assert!(matches!(err, PiperError::ModelNotFound(_)));
+        assert!(err.to_string().contains("/nonexistent/voice.onnx"));
+    }
+
+    // -- Decoding tests -----------------------------------------------------
+
+    #[test]
+    fn decode_raw_pcm() {
+        let clip = decode_output(&[0x00, 0x40, 0x00, 0xC0], 22050).unwrap();
+        assert_eq!(clip.data, vec![0.5, -0.5]);
+        assert_eq!(clip.sample_rate, 22050);
+        assert_eq!(clip.channels, 1);
+        assert!((clip.duration() - 2.0 / 22050.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn decode_wav_output_uses_header_rate() {
+        let wav = AudioClip {
+            data: vec![0.0, 0.25, -0.25],
+            duration: 3.0 / 16000.0,
+            sample_rate: 16000,
+            channels: 1,
+        }
+        .to_wav_bytes();
+
+        let clip = decode_output(&wav, 22050).unwrap();
+        assert_eq!(clip.sample_rate, 16000);
+        assert_eq!(clip.data.len(), 3);
+        assert!((clip.data[1] - 0.25).abs() < 1e-3);
+    }
+
+    #[test]
+    fn decode_rejects_empty_and_odd_output() {
+        assert!(matches!(decode_output(&[], 22050), Err(PiperError::InvalidOutput(_))));
+        assert!(matches!(decode_output(&[1, 2, 3], 22050), Err(PiperError::InvalidOutput(_))));
+    }
+
+    // -- Backend tests (stub executable) ------------------------------------
+
+    #[cfg(unix)]
+    mod stub_tests {
+        use super::*;
+        use std::os::unix::fs::PermissionsExt;
+
+        /// Write an executable shell script standing in for `piper`, plus an
+        /// empty model file, into a fresh temp directory.
+        fn stub(name: &str, script: &str) -> PiperConfig {
+            let dir = std::env::temp_dir().join(format!("moron_test_piper_{name}"));
+            let _ = std::fs::remove_dir_all(&dir);
+            std::fs::create_dir_all(&dir).unwrap();
+
+            let exe = dir.join("piper");
+            std::fs::write(&exe, format!("#!/bin/sh\n{script}\n")).unwrap();
+            std::fs::set_permissions(&exe, std::fs::Permissions::from_mode(0o755)).unwrap();
+
+            let model = dir.join("voice.onnx");
+            std::fs::write(&model, b"fake").unwrap();
+
+            PiperConfig::new(model).with_executable(exe)
+        }
+
+        #[test]
+        fn synthesize_reads_raw_stdout() {
+            // Consume the text, then emit two samples: 0.5 and -0.5.
+            let config = stub("raw", r"cat > /dev/null; printf '\000\100\000\300'");
+            let backend = PiperBackend::new(config);
+
+            let clip = backend.synthesize("hello there").unwrap();
+            assert_eq!(clip.data, vec![0.5, -0.5]);
+            assert_eq!(clip.sample_rate, PIPER_SAMPLE_RATE);
+            assert_eq!(backend.name(), "piper");
+        }
+
+        #[test]
+        fn synthesize_passes_text_on_stdin() {
+            // Fail with the received line on stderr so the test can see it.
+            let config = stub("stdin", "read line; echo \"got: $line\" >&2; exit 3");
+            let backend = PiperBackend::new(config);
+
+            let err = backend.run("multi\nline  text").unwrap_err();
+            match err {
+                PiperError::ProcessFailed { status, stderr } => {
+                    assert_eq!(status, "exit code 3");
+                    assert_eq!(stderr, "got: multi line text");
+                }
+                other => panic!("expected ProcessFailed, got {other:?}"),
+            }
+        }
+
+        #[test]
+        fn synthesize_passes_arguments() {
+            let config = stub("args", "cat > /dev/null; echo \"$@\" >&2; exit 1").with_speaker(7);
+            let backend = PiperBackend::new(config);
+
+            let err = backend.run("hi").unwrap_err().to_string();
+            assert!(err.contains("--output-raw"), "got: {err}");
+            assert!(err.contains("--speaker 7"), "got: {err}");
+        }
+    }
+
+    #[test]
+    fn synthesize_missing_executable() {
+        let dir = std::env::temp_dir().join("moron_test_piper_missing_exe");
+        std::fs::create_dir_all(&dir).unwrap();
+        let model = dir.join("voice.onnx");
+        std::fs::write(&model, b"fake").unwrap();
+
+        let backend = PiperBackend::new(
+            PiperConfig::new(&model).with_executable("/nonexistent/piper"),
+        );
+        let err = backend.run("hello").unwrap_err();
+        assert!(matches!(err, PiperError::SpawnFailed { .. }));
+    }
+
+    #[test]
+    fn synthesize_empty_text_returns_error() {
+        let backend = PiperBackend::new(PiperConfig::new("/tmp/voice.onnx"));
+        assert!(matches!(backend.run("  \n"), Err(PiperError::EmptyText)));
+    }
+}
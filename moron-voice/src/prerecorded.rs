@@ -0,0 +1,352 @@
+//! Pre-recorded backend: human voiceover from WAV files.
+//!
+//! Stands in for TTS so that recorded narration drives scene timing the same
+//! way synthesized speech does. The source is either a directory holding one
+//! WAV file per narration (taken in file-name order), or a single WAV file
+//! split into narrations at its cue markers (as written by most audio
+//! editors). Each call to [`synthesize`](VoiceBackend::synthesize) returns the
+//! next section.
+//!
+//! The text passed in is ignored: sections are matched to narrations purely
+//! by order. One backend must therefore serve every narration recorded with
+//! its file, in timeline order, and there should be exactly as many
+//! narrations as sections ([`recorded_sections`](VoiceBackend::recorded_sections)
+//! reports the count so builds can check). For the same reason a cache would
+//! skip calls and shift every later section, so
+//! [`CachingBackend`](crate::CachingBackend) passes this backend's calls
+//! straight through.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use moron_voice::{PreRecordedBackend, VoiceBackend};
+//!
+//! // One recording, cut into narrations at its cue markers.
+//! let backend = PreRecordedBackend::open("voiceover/take3.wav").unwrap();
+//! let intro = backend.synthesize("Welcome to moron.").unwrap();
+//! let next = backend.synthesize("Let's get started.").unwrap();
+//! ```
+
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use crate::audio::{wav_chunks, AudioClip, AudioError};
+use crate::backend::VoiceBackend;
+
+// ---------------------------------------------------------------------------
+// Errors
+// ---------------------------------------------------------------------------
+
+/// Errors specific to the pre-recorded backend.
+#[derive(Debug, thiserror::Error)]
+pub enum PreRecordedError {
+    /// The recording could not be read.
+    #[error("failed to read recording {path}: {source}")]
+    Io {
+        /// The file that was read.
+        path: PathBuf,
+        /// The underlying I/O error.
+        source: std::io::Error,
+    },
+
+    /// The recording is not a decodable WAV file.
+    #[error("failed to decode recording {path}: {source}")]
+    Decode {
+        /// The file that was decoded.
+        path: PathBuf,
+        /// The underlying decoding error.
+        source: AudioError,
+    },
+
+    /// More narrations asked for audio than the recording has sections.
+    #[error("recording {path} has only {available} section(s)")]
+    Exhausted {
+        /// The recording that ran out.
+        path: PathBuf,
+        /// Number of sections the recording holds.
+        available: usize,
+    },
+}
+
+// ---------------------------------------------------------------------------
+// Backend
+// ---------------------------------------------------------------------------
+
+/// Plays back sections of a recording in narration order.
+pub struct PreRecordedBackend {
+    /// Where the recording came from (for error messages).
+    path: PathBuf,
+    /// The recording split at its cue markers.
+    sections: Vec<AudioClip>,
+    /// Index of the section handed out by the next `synthesize` call.
+    next: AtomicUsize,
+}
+
+impl PreRecordedBackend {
+    /// Load a recording from `path`.
+    ///
+    /// A directory yields one section per `.wav` file, sorted by file name.
+    /// A file is split at its cue markers; without markers it is a single
+    /// section.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PreRecordedError`] if a file can't be read or decoded.
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, PreRecordedError> {
+        let path = path.as_ref().to_path_buf();
+        if !path.is_dir() {
+            let (clip, cues) = read_wav(&path)?;
+            return Ok(Self::from_clip(path, clip, &cues));
+        }
+
+        let io_error = |source| PreRecordedError::Io {
+            path: path.clone(),
+            source,
+        };
+        let mut files = Vec::new();
+        for entry in std::fs::read_dir(&path).map_err(io_error)? {
+            let file = entry.map_err(io_error)?.path();
+            let is_wav = file
+                .extension()
+                .is_some_and(|ext| ext.eq_ignore_ascii_case("wav"));
+            if is_wav && file.is_file() {
+                files.push(file);
+            }
+        }
+        files.sort();
+
+        let sections = files
+            .iter()
+            .map(|file| read_wav(file).map(|(clip, _)| clip.to_mono()))
+            .collect::<Result<_, _>>()?;
+        Ok(Self {
+            path,
+            sections,
+            next: AtomicUsize::new(0),
+        })
+    }
+
+    /// Build a backend from an already-decoded clip.
+    ///
+    /// `cues` are sample-frame offsets where a new section starts; they need
+    /// not be sorted, and offsets at the start or past the end are ignored.
+    pub fn from_clip(path: impl Into<PathBuf>, clip: AudioClip, cues: &[u32]) -> Self {
+        Self {
+            path: path.into(),
+            sections: split_at_cues(&clip.to_mono(), cues),
+            next: AtomicUsize::new(0),
+        }
+    }
+
+    /// Number of sections (narrations) in the recording.
+    pub fn section_count(&self) -> usize {
+        self.sections.len()
+    }
+}
+
+impl VoiceBackend for PreRecordedBackend {
+    fn synthesize(&self, _text: &str) -> Result<AudioClip, anyhow::Error> {
+        let index = self.next.fetch_add(1, Ordering::SeqCst);
+        let section = self.sections.get(index).ok_or_else(|| PreRecordedError::Exhausted {
+            path: self.path.clone(),
+            available: self.sections.len(),
+        })?;
+        Ok(section.clone())
+    }
+
+    fn recorded_sections(&self) -> Option<usize> {
+        Some(self.sections.len())
+    }
+
+    fn name(&self) -> &str {
+        "pre-recorded"
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Helpers
+// ---------------------------------------------------------------------------
+
+/// Read and decode a WAV file, returning the clip and its cue points.
+fn read_wav(path: &Path) -> Result<(AudioClip, Vec<u32>), PreRecordedError> {
+    let bytes = std::fs::read(path).map_err(|source| PreRecordedError::Io {
+        path: path.to_path_buf(),
+        source,
+    })?;
+    let decode_error = |source| PreRecordedError::Decode {
+        path: path.to_path_buf(),
+        source,
+    };
+
+    let clip = AudioClip::from_wav_bytes(&bytes).map_err(decode_error)?;
+    let cues = wav_cue_points(&bytes).map_err(decode_error)?;
+    Ok((clip, cues))
+}
+
+/// Read the sample-frame offsets of the cue points in a WAV file's `cue `
+/// chunk, or an empty list if it has none.
+fn wav_cue_points(bytes: &[u8]) -> Result<Vec<u32>, AudioError> {
+    let Some((_, cue)) = wav_chunks(bytes)?.into_iter().find(|(id, _)| id == b"cue ") else {
+        return Ok(Vec::new());
+    };
+
+    // Each cue point is 24 bytes; its sample offset is the last field.
+    let points = cue
+        .get(4..)
+        .unwrap_or_default()
+        .chunks_exact(24)
+        .map(|p| u32::from_le_bytes([p[20], p[21], p[22], p[23]]))
+        .collect();
+    Ok(points)
+}
+
+/// Cut a mono clip into consecutive sections starting at each cue offset.
+fn split_at_cues(clip: &AudioClip, cues: &[u32]) -> Vec<AudioClip> {
+    let len = clip.data.len();
+    let mut bounds: Vec<usize> = cues
+        .iter()
+        .map(|&c| c as usize)
+        .filter(|&c| c > 0 && c < len)
+        .collect();
+    bounds.sort_unstable();
+    bounds.dedup();
+    bounds.insert(0, 0);
+    bounds.push(len);
+
+    bounds
+        .windows(2)
+        .map(|w| {
+            let data = clip.data[w[0]..w[1]].to_vec();
+            AudioClip {
+                duration: data.len() as f64 / clip.sample_rate as f64,
+                data,
+                sample_rate: clip.sample_rate,
+                channels: 1,
+            }
+        })
+        .collect()
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A mono clip at 1 kHz whose sample values count up from 0.
+    fn ramp(samples: usize) -> AudioClip {
+        AudioClip {
+            data: (0..samples).map(|i| i as f32 / 1000.0).collect(),
+            duration: samples as f64 / 1000.0,
+            sample_rate: 1000,
+            channels: 1,
+        }
+    }
+
+    /// Append a `cue ` chunk with the given sample offsets to WAV bytes.
+    fn with_cue_chunk(mut wav: Vec<u8>, offsets: &[u32]) -> Vec<u8> {
+        let size = 4 + 24 * offsets.len() as u32;
+        wav.extend_from_slice(b"cue ");
+        wav.extend_from_slice(&size.to_le_bytes());
+        wav.extend_from_slice(&(offsets.len() as u32).to_le_bytes());
+        for (i, &offset) in offsets.iter().enumerate() {
+            wav.extend_from_slice(&(i as u32 + 1).to_le_bytes()); // id
+            wav.extend_from_slice(&offset.to_le_bytes()); // position
+            wav.extend_from_slice(b"data");
+            wav.extend_from_slice(&0u32.to_le_bytes()); // chunk start
+            wav.extend_from_slice(&0u32.to_le_bytes()); // block start
+            wav.extend_from_slice(&offset.to_le_bytes()); // sample offset
+        }
+        let riff_size = wav.len() as u32 - 8;
+        wav[4..8].copy_from_slice(&riff_size.to_le_bytes());
+        wav
+    }
+
+    #[test]
+    fn whole_file_without_cues() {
+        let backend = PreRecordedBackend::from_clip("vo.wav", ramp(500), &[]);
+        assert_eq!(backend.section_count(), 1);
+
+        let clip = backend.synthesize("anything").unwrap();
+        assert_eq!(clip.data.len(), 500);
+        assert!((clip.duration() - 0.5).abs() < 1e-12);
+    }
+
+    #[test]
+    fn sections_follow_cues_in_order() {
+        let backend = PreRecordedBackend::from_clip("vo.wav", ramp(1000), &[700, 0, 250, 5000]);
+        assert_eq!(backend.section_count(), 3);
+        assert_eq!(backend.recorded_sections(), Some(3));
+
+        let a = backend.synthesize("one").unwrap();
+        let b = backend.synthesize("two").unwrap();
+        let c = backend.synthesize("three").unwrap();
+        assert_eq!(a.data.len(), 250);
+        assert_eq!(b.data.len(), 450);
+        assert_eq!(c.data.len(), 300);
+        assert!((b.data[0] - 0.25).abs() < f32::EPSILON);
+        assert!((c.duration() - 0.3).abs() < 1e-12);
+    }
+
+    #[test]
+    fn too_many_narrations_is_an_error() {
+        let backend = PreRecordedBackend::from_clip("vo.wav", ramp(10), &[]);
+        backend.synthesize("one").unwrap();
+        let err = backend.synthesize("two").unwrap_err();
+        assert!(err.to_string().contains("only 1 section"), "got: {err}");
+    }
+
+    #[test]
+    fn stereo_is_downmixed() {
+        let stereo = AudioClip {
+            data: vec![0.5, 0.0, -0.5, -0.5],
+            duration: 2.0 / 1000.0,
+            sample_rate: 1000,
+            channels: 2,
+        };
+        let backend = PreRecordedBackend::from_clip("vo.wav", stereo, &[]);
+        let clip = backend.synthesize("x").unwrap();
+        assert_eq!(clip.channels, 1);
+        assert_eq!(clip.data, vec![0.25, -0.5]);
+    }
+
+    #[test]
+    fn open_reads_cue_markers() {
+        let dir = std::env::temp_dir().join("moron_test_prerecorded_open");
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("vo.wav");
+        std::fs::write(&path, with_cue_chunk(ramp(400).to_wav_bytes(), &[100, 300])).unwrap();
+
+        let backend = PreRecordedBackend::open(&path).unwrap();
+        assert_eq!(backend.section_count(), 3);
+        assert_eq!(backend.synthesize("a").unwrap().data.len(), 100);
+        assert_eq!(backend.synthesize("b").unwrap().data.len(), 200);
+        assert_eq!(backend.synthesize("c").unwrap().data.len(), 100);
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn open_directory_one_file_per_narration() {
+        let dir = std::env::temp_dir().join("moron_test_prerecorded_dir");
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("02-outro.wav"), ramp(300).to_wav_bytes()).unwrap();
+        std::fs::write(dir.join("01-intro.wav"), ramp(100).to_wav_bytes()).unwrap();
+        std::fs::write(dir.join("notes.txt"), b"not audio").unwrap();
+
+        let backend = PreRecordedBackend::open(&dir).unwrap();
+        assert_eq!(backend.section_count(), 2);
+        assert_eq!(backend.synthesize("intro").unwrap().data.len(), 100);
+        assert_eq!(backend.synthesize("outro").unwrap().data.len(), 300);
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn open_missing_file() {
+        let err = PreRecordedBackend::open("/nonexistent/vo.wav").err().unwrap();
+        assert!(matches!(err, PreRecordedError::Io { .. }));
+    }
+}
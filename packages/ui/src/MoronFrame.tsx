@@ -1,6 +1,8 @@
 import type { CSSProperties, ReactNode } from "react";
 
-import type { ElementState, FrameState, ItemState } from "./types";
+import type { ElementState, FrameState, ItemState, PaneContent } from "./types";
+import { renderCustomComponent } from "./templates/components";
+import { Captions } from "./components/Captions";
 
 // ---------------------------------------------------------------------------
 // Props
@@ -40,9 +42,9 @@ function buildTransform(el: ElementState): string {
 }
 
 /**
- * Build a CSS `transform` string from an item's per-item visual state.
+ * Build a CSS `transform` string from an item's (or pane's) visual state.
  */
-function buildItemTransform(item: ItemState): string {
+function buildItemTransform(item: Omit<ItemState, "text">): string {
   const parts: string[] = [];
 
   if (item.translateX !== 0 || item.translateY !== 0) {
@@ -62,6 +64,50 @@ function buildItemTransform(item: ItemState): string {
 // Content renderers
 // ---------------------------------------------------------------------------
 
+/**
+ * Render the body of one Compare pane.
+ */
+function renderPaneContent(content: PaneContent): ReactNode {
+  switch (content.type) {
+    case "text":
+      return (
+        <p style={{ margin: 0, fontSize: "var(--moron-text-xl)", lineHeight: 1.5 }}>
+          {content.text}
+        </p>
+      );
+    case "metric":
+      return (
+        <span
+          data-moron="metric-value"
+          data-direction={content.direction}
+          style={{
+            fontSize: "var(--moron-text-4xl)",
+            fontWeight: "var(--moron-font-weight-bold)" as CSSProperties["fontWeight"],
+            lineHeight: 1.2,
+          }}
+        >
+          {content.value}
+        </span>
+      );
+    case "steps":
+      return (
+        <div
+          style={{
+            display: "flex",
+            flexDirection: "column",
+            gap: "var(--moron-space-2)",
+            alignItems: "flex-start",
+            fontSize: "var(--moron-text-lg)",
+          }}
+        >
+          {content.items.map((item, i) => (
+            <div key={i}>{item}</div>
+          ))}
+        </div>
+      );
+  }
+}
+
 /**
  * Render the inner content of an element based on its kind.
  * Uses var(--moron-*) CSS custom properties for all styling.
@@ -196,6 +242,72 @@ function renderContent(el: ElementState): ReactNode {
         </div>
       );
     }
+
+    case "component":
+      return renderCustomComponent(el);
+
+    case "annotation":
+      return (
+        <div
+          data-moron="annotation"
+          data-target={kind.target}
+          style={{
+            display: "flex",
+            alignItems: "center",
+            gap: "var(--moron-space-2)",
+            padding: "var(--moron-space-2) var(--moron-space-4)",
+            borderRadius: "var(--moron-radius-md)",
+            background: "var(--moron-accent)",
+            color: "var(--moron-bg-primary)",
+            fontSize: "var(--moron-text-lg)",
+            whiteSpace: "nowrap",
+          }}
+        >
+          <span aria-hidden="true">{"\u2190"}</span>
+          {el.content}
+        </div>
+      );
+
+    case "compare":
+      return (
+        <div
+          data-moron="compare"
+          style={{
+            display: "flex",
+            flexDirection: "row",
+            alignItems: "stretch",
+            gap: "var(--moron-space-12)",
+          }}
+        >
+          {el.panes.map((pane, i) => {
+            const paneTransform = buildItemTransform(pane);
+            return (
+              <div
+                key={i}
+                data-moron="compare-pane"
+                data-index={i}
+                style={{
+                  display: "flex",
+                  flexDirection: "column",
+                  alignItems: "center",
+                  gap: "var(--moron-space-4)",
+                  minWidth: "24em",
+                  opacity: pane.opacity,
+                  transform: paneTransform !== "none" ? paneTransform : undefined,
+                }}
+              >
+                <span
+                  data-moron="compare-label"
+                  style={{ fontSize: "var(--moron-text-lg)", opacity: 0.8 }}
+                >
+                  {pane.label}
+                </span>
+                {renderPaneContent(pane.content)}
+              </div>
+            );
+          })}
+        </div>
+      );
   }
 }
 
@@ -252,16 +364,25 @@ export function MoronFrame({
           ? `translate(-50%, -50%) ${animTransform}`
           : "translate(-50%, -50%)";
 
+        // Annotations sit to the right of their target, at the same height.
+        const isAnnotation = el.kind.type === "annotation";
+
+        // Highlight ring around the focused element, scaled by intensity.
+        const focused = state.focus !== null && state.focus.targetId === el.id;
+
         const wrapperStyle: CSSProperties = {
           position: "absolute",
           top: `${el.layoutY * 100}%`,
-          left: "50%",
+          left: isAnnotation ? "78%" : "50%",
           transform: centerAndAnim,
           maxWidth: "80%",
           opacity: el.opacity,
           zIndex: index,
           pointerEvents: "none",
           textAlign: "center",
+          filter: focused && state.focus
+            ? `drop-shadow(0 0 ${24 * state.focus.intensity}px var(--moron-accent))`
+            : undefined,
         };
 
         return (
@@ -275,6 +396,9 @@ export function MoronFrame({
           </div>
         );
       })}
+      {state.captions && (
+        <Captions captions={state.captions} zIndex={state.elements.length} />
+      )}
     </div>
   );
 }
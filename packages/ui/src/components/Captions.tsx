@@ -0,0 +1,66 @@
+import type { CSSProperties } from "react";
+
+import type { CaptionState } from "../types";
+
+export interface CaptionsProps {
+  /** The caption from `FrameState.captions`. */
+  captions: CaptionState;
+  /** Stacking order; templates pass the element count to draw on top. */
+  zIndex?: number;
+  className?: string;
+  style?: CSSProperties;
+}
+
+/**
+ * Burned-in subtitle bar for a Moron frame.
+ * Words already spoken use the accent color, karaoke style; the word being
+ * spoken is marked with `data-active`.
+ */
+export function Captions({ captions, zIndex, className, style }: CaptionsProps) {
+  const active = captions.activeWord ?? -1;
+  let index = 0;
+
+  return (
+    <div
+      data-moron="captions"
+      className={className}
+      style={{
+        position: "absolute",
+        left: "50%",
+        bottom: "6%",
+        transform: "translateX(-50%)",
+        maxWidth: "80%",
+        padding: "var(--moron-space-2) var(--moron-space-4)",
+        background: "rgba(0, 0, 0, 0.6)",
+        color: "#ffffff",
+        fontSize: "var(--moron-text-lg)",
+        lineHeight: 1.3,
+        textAlign: "center",
+        pointerEvents: "none",
+        zIndex,
+        ...style,
+      }}
+    >
+      {captions.lines.map((line, lineIndex) => (
+        <div key={lineIndex} data-moron="caption-line">
+          {line.split(" ").map((word, wordIndex) => {
+            const current = index++;
+            return (
+              <span
+                key={wordIndex}
+                data-moron="caption-word"
+                data-active={current === active || undefined}
+                style={{
+                  color: current <= active ? "var(--moron-accent)" : undefined,
+                }}
+              >
+                {wordIndex > 0 ? " " : ""}
+                {word}
+              </span>
+            );
+          })}
+        </div>
+      ))}
+    </div>
+  );
+}
@@ -11,15 +11,22 @@ export type { SequenceProps } from "./components/Sequence";
 export { Metric } from "./components/Metric";
 export type { MetricProps } from "./components/Metric";
 
+export { Captions } from "./components/Captions";
+export type { CaptionsProps } from "./components/Captions";
+
 // Frame rendering
 export { MoronFrame } from "./MoronFrame";
 export type { MoronFrameProps } from "./MoronFrame";
 
 // Frame state types (Rust FrameState JSON contract)
 export type {
+  CaptionState,
   FrameState,
   ElementState,
   ElementKind,
+  FocusState,
+  PaneContent,
+  PaneState,
   ThemeState,
 } from "./types";
 
@@ -32,5 +39,14 @@ export {
 
 export type { TemplateComponent, TemplateProps } from "./templates";
 
+// Custom components for m.diagram(name, props)
+export {
+  registerComponent,
+  getComponent,
+  listComponents,
+} from "./templates";
+
+export type { CustomComponent, CustomComponentProps } from "./templates";
+
 // Template implementations
 export { ExplainerTemplate } from "./templates/ExplainerTemplate";
@@ -1,8 +1,8 @@
 /**
  * Explainer template for Moron scenes.
  *
- * A production-quality template that renders all 5 element types (title,
- * section, show, metric, steps) with polished visual styling. All colors
+ * A production-quality template that renders all 8 element types (title,
+ * section, show, metric, steps, component, compare, annotation) with polished visual styling. All colors
  * and typography reference var(--moron-*) CSS custom properties from the
  * theme system.
  *
@@ -12,6 +12,8 @@
 import type { CSSProperties, ReactNode } from "react";
 
 import type { ElementState, FrameState } from "../types";
+import { Captions } from "../components/Captions";
+import { renderCustomComponent } from "./components";
 import { registerTemplate } from "./registry";
 import type { TemplateProps } from "./registry";
 
@@ -301,6 +303,137 @@ function ExplainerSteps({ el }: { el: ElementState }): ReactNode {
   );
 }
 
+/**
+ * Before/after comparison: two cards side by side with an accent divider.
+ * Each pane carries its own transforms so techniques can reveal them in turn.
+ */
+function ExplainerCompare({ el }: { el: ElementState }): ReactNode {
+  return (
+    <div
+      data-moron="explainer-compare"
+      style={{
+        display: "flex",
+        alignItems: "stretch",
+        justifyContent: "center",
+        gap: "var(--moron-space-8)",
+        padding: "var(--moron-container-padding)",
+      }}
+    >
+      {el.panes.map((pane, i) => {
+        const paneTransform = buildTransform({ ...el, ...pane });
+        return (
+          <div
+            key={i}
+            data-moron="explainer-compare-pane"
+            data-index={i}
+            style={{
+              display: "flex",
+              flexDirection: "column",
+              alignItems: "center",
+              gap: "var(--moron-space-4)",
+              minWidth: "28em",
+              background: "var(--moron-bg-secondary)",
+              borderRadius: "var(--moron-radius-lg)",
+              borderTop: i === 0
+                ? "4px solid var(--moron-fg-muted)"
+                : "4px solid var(--moron-accent)",
+              padding: "var(--moron-space-8)",
+              boxShadow: "var(--moron-shadow-md)",
+              opacity: pane.opacity,
+              transform: paneTransform !== "none" ? paneTransform : undefined,
+            }}
+          >
+            <span
+              style={{
+                fontSize: "var(--moron-text-base)",
+                fontWeight: "var(--moron-font-weight-semibold)" as CSSProperties["fontWeight"],
+                textTransform: "uppercase",
+                letterSpacing: "0.08em",
+                color: "var(--moron-fg-secondary)",
+              }}
+            >
+              {pane.label}
+            </span>
+            {pane.content.type === "text" && (
+              <span
+                style={{
+                  fontSize: "var(--moron-text-2xl)",
+                  lineHeight: "var(--moron-leading-normal)",
+                  color: "var(--moron-fg-primary)",
+                  textAlign: "center",
+                }}
+              >
+                {pane.content.text}
+              </span>
+            )}
+            {pane.content.type === "metric" && (
+              <span
+                data-direction={pane.content.direction}
+                style={{
+                  fontSize: "var(--moron-text-4xl)",
+                  fontWeight: "var(--moron-font-weight-bold)" as CSSProperties["fontWeight"],
+                  lineHeight: "var(--moron-leading-tight)",
+                  color: pane.content.direction === "up"
+                    ? "var(--moron-success)"
+                    : pane.content.direction === "down"
+                      ? "var(--moron-error)"
+                      : "var(--moron-fg-primary)",
+                }}
+              >
+                {pane.content.value}
+              </span>
+            )}
+            {pane.content.type === "steps" && (
+              <div
+                style={{
+                  display: "flex",
+                  flexDirection: "column",
+                  gap: "var(--moron-space-3)",
+                  fontSize: "var(--moron-text-lg)",
+                  color: "var(--moron-fg-primary)",
+                }}
+              >
+                {pane.content.items.map((item, j) => (
+                  <span key={j}>{item}</span>
+                ))}
+              </div>
+            )}
+          </div>
+        );
+      })}
+    </div>
+  );
+}
+
+/**
+ * Focus callout: an accent bubble offset to the right of center, pointing
+ * back at the highlighted element.
+ */
+function ExplainerAnnotation({ el }: { el: ElementState }): ReactNode {
+  return (
+    <div
+      data-moron="explainer-annotation"
+      style={{
+        marginLeft: "50%",
+        display: "flex",
+        alignItems: "center",
+        gap: "var(--moron-space-3)",
+        padding: "var(--moron-space-3) var(--moron-space-6)",
+        borderRadius: "var(--moron-radius-lg)",
+        background: "var(--moron-accent)",
+        color: "var(--moron-bg-primary)",
+        boxShadow: "var(--moron-shadow-lg)",
+        fontSize: "var(--moron-text-xl)",
+        fontWeight: "var(--moron-font-weight-medium)" as CSSProperties["fontWeight"],
+        whiteSpace: "nowrap",
+      }}
+    >
+      <span aria-hidden="true">{"\u2190"}</span>
+      {el.content}
+    </div>
+  );
+}
+
 // ---------------------------------------------------------------------------
 // Content router
 // ---------------------------------------------------------------------------
@@ -317,6 +450,12 @@ function renderExplainerContent(el: ElementState): ReactNode {
       return <ExplainerMetric el={el} />;
     case "steps":
       return <ExplainerSteps el={el} />;
+    case "component":
+      return renderCustomComponent(el);
+    case "compare":
+      return <ExplainerCompare el={el} />;
+    case "annotation":
+      return <ExplainerAnnotation el={el} />;
   }
 }
 
@@ -327,7 +466,7 @@ function renderExplainerContent(el: ElementState): ReactNode {
 /**
  * Renders a polished explainer-style frame from FrameState.
  *
- * Supports all 5 element kinds with professional visual treatment.
+ * Supports all 8 element kinds with professional visual treatment.
  * All styling uses var(--moron-*) CSS custom properties — no hardcoded
  * colors. Responsive to frame dimensions via proportional root font-size.
  */
@@ -376,6 +515,10 @@ export function ExplainerTemplate({
           transform: buildTransform(el),
           zIndex: index,
           pointerEvents: "none",
+          // Highlight ring around the focused element, scaled by intensity.
+          filter: state.focus && state.focus.targetId === el.id
+            ? `drop-shadow(0 0 ${24 * state.focus.intensity}px var(--moron-accent))`
+            : undefined,
         };
 
         return (
@@ -389,6 +532,9 @@ export function ExplainerTemplate({
           </div>
         );
       })}
+      {state.captions && (
+        <Captions captions={state.captions} zIndex={state.elements.length} />
+      )}
     </div>
   );
 }
@@ -0,0 +1,116 @@
+/**
+ * Custom component registration for `m.diagram(name, props)` elements.
+ *
+ * Rust emits `{ type: "component", name, props }` element kinds. Templates
+ * look the name up here and render the registered React component with the
+ * props passed through unchanged, so teams can ship their own visuals
+ * without touching moron-core.
+ */
+
+import type { ComponentType, ReactNode } from "react";
+
+import type { ElementState } from "../types";
+
+// ---------------------------------------------------------------------------
+// Types
+// ---------------------------------------------------------------------------
+
+/**
+ * Props that every custom component receives.
+ */
+export interface CustomComponentProps {
+  /** The JSON props given to `m.diagram(name, props)` on the Rust side. */
+  props: unknown;
+  /** The full element state (transforms are applied by the template wrapper). */
+  element: ElementState;
+}
+
+/**
+ * A React component that renders a `component` element.
+ */
+export type CustomComponent = ComponentType<CustomComponentProps>;
+
+// ---------------------------------------------------------------------------
+// Registry
+// ---------------------------------------------------------------------------
+
+const registry = new Map<string, CustomComponent>();
+
+/**
+ * Register a custom component under the name used in `m.diagram(name, ...)`.
+ *
+ * If a component with the same name is already registered, it is replaced
+ * and a warning is logged, matching `registerTemplate`.
+ *
+ * @param name - Component name referenced from Rust
+ * @param component - React component conforming to CustomComponent
+ */
+export function registerComponent(
+  name: string,
+  component: CustomComponent,
+): void {
+  if (registry.has(name)) {
+    console.warn(
+      `[moron] Component "${name}" is already registered. Replacing.`,
+    );
+  }
+  registry.set(name, component);
+}
+
+/**
+ * Retrieve a custom component by name, or undefined if none is registered.
+ *
+ * @param name - Component name to look up
+ */
+export function getComponent(name: string): CustomComponent | undefined {
+  return registry.get(name);
+}
+
+/**
+ * List all registered custom component names.
+ *
+ * @returns Array of registered component name strings
+ */
+export function listComponents(): string[] {
+  return Array.from(registry.keys());
+}
+
+// ---------------------------------------------------------------------------
+// Rendering
+// ---------------------------------------------------------------------------
+
+/**
+ * Render a `component` element through the registry.
+ *
+ * Unknown names render a visible placeholder instead of failing the frame,
+ * so a missing registration shows up in the output rather than as a blank.
+ */
+export function renderCustomComponent(el: ElementState): ReactNode {
+  if (el.kind.type !== "component") {
+    return null;
+  }
+
+  const Component = registry.get(el.kind.name);
+  if (!Component) {
+    return (
+      <div
+        data-moron="component-missing"
+        data-component={el.kind.name}
+        style={{
+          padding: "var(--moron-space-4)",
+          border: "2px dashed var(--moron-fg-muted)",
+          color: "var(--moron-fg-muted)",
+          fontFamily: "var(--moron-font-mono)",
+        }}
+      >
+        Unknown component: {el.kind.name}
+      </div>
+    );
+  }
+
+  return (
+    <div data-moron="component" data-component={el.kind.name}>
+      <Component props={el.kind.props} element={el} />
+    </div>
+  );
+}
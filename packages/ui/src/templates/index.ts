@@ -18,5 +18,13 @@ export {
 
 export type { TemplateComponent, TemplateProps } from "./registry";
 
+export {
+  registerComponent,
+  getComponent,
+  listComponents,
+} from "./components";
+
+export type { CustomComponent, CustomComponentProps } from "./components";
+
 // Template implementations
 export { ExplainerTemplate } from "./ExplainerTemplate";
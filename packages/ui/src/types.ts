@@ -24,7 +24,10 @@ export type ElementKind =
   | { type: "show" }
   | { type: "section" }
   | { type: "metric"; direction: string }
-  | { type: "steps"; count: number };
+  | { type: "steps"; count: number }
+  | { type: "component"; name: string; props: unknown }
+  | { type: "compare" }
+  | { type: "annotation"; target: number };
 
 // ---------------------------------------------------------------------------
 // ItemState — per-item visual snapshot (for Steps elements)
@@ -51,6 +54,43 @@ export interface ItemState {
   rotation: number;
 }
 
+// ---------------------------------------------------------------------------
+// PaneState — per-pane visual snapshot (for Compare elements)
+// ---------------------------------------------------------------------------
+
+/**
+ * Content shown inside one pane of a Compare element.
+ *
+ * Rust: `frame::PaneContent` with `#[serde(tag = "type")]`.
+ */
+export type PaneContent =
+  | { type: "text"; text: string }
+  | { type: "metric"; value: string; direction: string }
+  | { type: "steps"; items: string[] };
+
+/**
+ * The visual state of one pane within a Compare element.
+ *
+ * Rust: `frame::PaneState` with `#[serde(rename_all = "camelCase")]`.
+ * Index 0 is the "before" (left) pane, index 1 the "after" (right) pane.
+ */
+export interface PaneState {
+  /** Heading shown above the pane. */
+  label: string;
+  /** What the pane displays. */
+  content: PaneContent;
+  /** Opacity: 0.0 = transparent, 1.0 = fully opaque. */
+  opacity: number;
+  /** Horizontal translation in pixels. */
+  translateX: number;
+  /** Vertical translation in pixels. */
+  translateY: number;
+  /** Scale factor: 1.0 = normal size. */
+  scale: number;
+  /** Rotation in degrees. */
+  rotation: number;
+}
+
 // ---------------------------------------------------------------------------
 // ElementState — per-element visual snapshot
 // ---------------------------------------------------------------------------
@@ -70,6 +110,8 @@ export interface ElementState {
   content: string;
   /** List items with per-item visual state (non-empty only for Steps elements). */
   items: ItemState[];
+  /** Comparison panes with per-pane visual state (non-empty only for Compare elements). */
+  panes: PaneState[];
   /** Whether this element is currently visible. */
   visible: boolean;
   /** Opacity: 0.0 = transparent, 1.0 = fully opaque. */
@@ -103,6 +145,47 @@ export interface ThemeState {
   cssProperties: Record<string, string>;
 }
 
+// ---------------------------------------------------------------------------
+// FocusState — the element currently drawn attention to
+// ---------------------------------------------------------------------------
+
+/**
+ * The active focus highlight, set while an `m.focus` annotation is on screen.
+ *
+ * Rust: `frame::FocusState` with `#[serde(rename_all = "camelCase")]`.
+ * Other elements are already dimmed in their `opacity`; templates only need
+ * to draw the highlight ring on the target.
+ */
+export interface FocusState {
+  /** ID of the focused element (where the highlight ring is drawn). */
+  targetId: number;
+  /** ID of the annotation element carrying the callout text. */
+  annotationId: number;
+  /** Highlight strength: 0.0 = off, 1.0 = fully on. */
+  intensity: number;
+}
+
+// ---------------------------------------------------------------------------
+// CaptionState — the subtitle on screen
+// ---------------------------------------------------------------------------
+
+/**
+ * The caption shown for burned-in subtitles.
+ *
+ * Rust: `frame::CaptionState` with `#[serde(rename_all = "camelCase")]`.
+ * Lines are already wrapped; templates highlight words up to `activeWord`
+ * for karaoke-style captions.
+ */
+export interface CaptionState {
+  /** The current caption, wrapped into lines. */
+  lines: string[];
+  /**
+   * Index of the word being spoken, counting words across all lines, or
+   * null before the first word of the caption is spoken.
+   */
+  activeWord: number | null;
+}
+
 // ---------------------------------------------------------------------------
 // FrameState — the complete visual state at a timestamp
 // ---------------------------------------------------------------------------
@@ -126,8 +209,13 @@ export interface FrameState {
   elements: ElementState[];
   /** Text of the currently active narration, or null if none. */
   activeNarration: string | null;
+  /** The caption for the active narration, or null if none or if burned-in
+   * captions are off for the build. Templates draw it only when present. */
+  captions: CaptionState | null;
   /** Current theme as CSS custom properties. */
   theme: ThemeState;
+  /** The active focus highlight, or null if nothing is in focus. */
+  focus: FocusState | null;
   /**
    * Template name to use for rendering this frame.
    * Maps to a registered template component via the template registry.